@@ -13,6 +13,19 @@ use std::{
 
 include!("src/cli/arguments.rs");
 
+// arguments refer to configuration types, which depend on the whole crate,
+// so only their command line shape is repeated here
+mod http_diff {
+    pub mod config {
+        #[derive(Clone, Debug, PartialEq, clap::ValueEnum)]
+        pub enum SavePolicy {
+            None,
+            Failed,
+            All,
+        }
+    }
+}
+
 struct PackageMeta {
     name: String,
     version: String,
@@ -53,7 +66,16 @@ fn build_manpages(
     let file = Path::new(&outdir).join(format!("{}.1", package_meta.name));
     let mut file = File::create(&file)?;
 
-    Man::new(app).render(&mut file)?;
+    Man::new(app.clone()).render(&mut file)?;
+
+    for subcommand in app.get_subcommands() {
+        let name = format!("{}-{}", package_meta.name, subcommand.get_name());
+
+        let file = Path::new(&outdir).join(format!("{}.1", name));
+        let mut file = File::create(&file)?;
+
+        Man::new(subcommand.clone()).title(name).render(&mut file)?;
+    }
 
     Ok(())
 }
@@ -44,7 +44,7 @@ EOF
 echo 'creating a release build'
 cargo build -r
 
-cp target/assets/$PACKAGE_NAME.1 $PATH_TO_MAN_DIR
+cp target/assets/$PACKAGE_NAME*.1 $PATH_TO_MAN_DIR
 cp target/release/$PACKAGE_NAME $PATH_TO_BIN_DIR
 
 cp assets/logo.png $PATH_TO_ICONS_DIR/$PACKAGE_NAME.png
@@ -24,7 +24,7 @@ class HttpDiffBin < Formula
 
   def install
     bin.install "http-diff"
-    man1.install "doc/http-diff.1"
+    man1.install Dir["doc/*.1"]
 
     bash_completion.install "complete/http-diff.bash"
     zsh_completion.install "complete/_http-diff"
@@ -1,6 +1,10 @@
 use crate::{
     app_state::{AppState, Screen},
-    http_diff::{config::Configuration, job::JobDTO, types::AppError},
+    http_diff::{
+        config::{Configuration, ConfigurationProblem},
+        job::JobDTO,
+        types::AppError,
+    },
     ui::notification::{Notification, NotificationId, NotificationType},
 };
 use crossterm::event::{
@@ -22,7 +26,7 @@ pub enum AppAction {
 
     ReloadConfigurationFile(String),
     TryLoadConfigurationFile(String),
-    ConfigurationLoaded(Configuration),
+    ConfigurationLoaded(Configuration, Vec<ConfigurationProblem>),
     GenerateDefaultConfiguration,
 
     LoadingJobsProgress((usize, usize)),
@@ -55,8 +59,11 @@ pub enum AppAction {
     ShowJobInfo(JobDTO),
     CloseJobInfoScreen,
 
-    SaveFailedJobs(Vec<JobDTO>),
+    SaveJobs(Vec<JobDTO>),
     SaveCurrentJob(JobDTO),
+    ExportCurrentJobAsCurl(JobDTO),
+    ExportCurrentJobAsHar(JobDTO),
+    ExportFailedJobsAsHar(Vec<JobDTO>),
 }
 
 pub fn event_to_app_action(
@@ -198,13 +205,38 @@ pub fn event_to_app_action(
                                 ),
                             ))
                         } else {
-                            Some(AppAction::SaveFailedJobs(failed_jobs))
+                            Some(AppAction::SaveJobs(failed_jobs))
                         }
                     }
+                    KeyCode::Char('A') if !app.jobs.is_empty() => {
+                        Some(AppAction::SaveJobs(app.jobs.clone()))
+                    }
                     KeyCode::Char('s') => match app.get_current_job() {
                         Some(job) => Some(AppAction::SaveCurrentJob(job)),
                         None => None,
                     },
+                    KeyCode::Char('c') => app
+                        .get_current_job()
+                        .map(AppAction::ExportCurrentJobAsCurl),
+                    KeyCode::Char('x') => app
+                        .get_current_job()
+                        .map(AppAction::ExportCurrentJobAsHar),
+                    KeyCode::Char('X') => {
+                        let failed_jobs = app.get_failed_jobs();
+
+                        if failed_jobs.is_empty() {
+                            Some(AppAction::SetNotification(
+                                Notification::new(
+                                    NotificationId::NoFailedJobs,
+                                    "There are no failed jobs to export",
+                                    Some(Duration::from_secs(5)),
+                                    NotificationType::Warning,
+                                ),
+                            ))
+                        } else {
+                            Some(AppAction::ExportFailedJobsAsHar(failed_jobs))
+                        }
+                    }
                     KeyCode::Char('g') => {
                         Some(AppAction::GenerateDefaultConfiguration)
                     }
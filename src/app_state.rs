@@ -1,7 +1,16 @@
 use crate::{
     actions::AppAction,
+    cli::OutputFormat,
     http_diff::{
-        config::{Configuration, DomainVariant},
+        config::{
+            format_problem, Configuration, ConfigurationProblem,
+            DomainVariant, EndpointFilter, SavePolicy,
+        },
+        export::{
+            map_job_to_record, map_jobs_to_run_record,
+            map_jobs_to_summary_record, save_har_archives, save_html_report,
+            save_junit_report, save_markdown_report,
+        },
         job::JobDTO,
         request::ResponseVariant,
         types::{AppError, JobStatus},
@@ -26,6 +35,9 @@ use std::{
 };
 use tracing::{error, warn};
 
+/// Warnings listed in notification, the rest are only counted
+const MAX_NOTIFIED_WARNINGS: usize = 3;
+
 pub enum Screen {
     Home,
     JobInfo,
@@ -52,6 +64,19 @@ pub struct AppState {
     pub current_theme: ThemeType,
     pub theme: Theme,
     pub is_headless_mode: bool,
+    pub should_export_har: bool,
+    pub junit_report_path: Option<PathBuf>,
+    pub html_report_path: Option<PathBuf>,
+    pub markdown_report_path: Option<PathBuf>,
+    pub markdown_report_size_limit: usize,
+    pub output_format: OutputFormat,
+    pub save_policy: Option<SavePolicy>,
+    pub configured_save_policy: Option<SavePolicy>,
+    pub endpoint_filter: EndpointFilter,
+    pub active_profile: Option<String>,
+    /// default configuration is generated to this path, in its format
+    pub configuration_path: String,
+    pub configuration_warnings: Vec<ConfigurationProblem>,
 }
 
 impl AppState {
@@ -83,6 +108,18 @@ impl AppState {
             critical_exception: None,
 
             is_headless_mode,
+            should_export_har: false,
+            junit_report_path: None,
+            html_report_path: None,
+            markdown_report_path: None,
+            markdown_report_size_limit: 0,
+            output_format: OutputFormat::Text,
+            save_policy: None,
+            configured_save_policy: None,
+            endpoint_filter: EndpointFilter::default(),
+            active_profile: None,
+            configuration_path: "./configuration.json".to_owned(),
+            configuration_warnings: Vec::new(),
         }
     }
 
@@ -227,6 +264,127 @@ impl AppState {
         None
     }
 
+    /// Writes exports requested by cli flags once all jobs are finished
+    pub fn export_run_results(&self) {
+        if self.should_export_har {
+            self.print_export_result(
+                "HAR archive",
+                save_har_archives(&self.jobs, &self.output_directory),
+            );
+        }
+
+        if let Some(report_path) = &self.junit_report_path {
+            self.print_export_result(
+                "JUnit report",
+                save_junit_report(&self.jobs, report_path)
+                    .map(|()| vec![report_path.clone()]),
+            );
+        }
+
+        if let Some(report_path) = &self.html_report_path {
+            self.print_export_result(
+                "HTML report",
+                save_html_report(&self.jobs, report_path)
+                    .map(|()| vec![report_path.clone()]),
+            );
+        }
+
+        if let Some(report_path) = &self.markdown_report_path {
+            self.print_export_result(
+                "markdown report",
+                save_markdown_report(
+                    &self.jobs,
+                    report_path,
+                    self.markdown_report_size_limit,
+                )
+                .map(|()| vec![report_path.clone()]),
+            );
+        }
+    }
+
+    fn print_export_result(
+        &self,
+        title: &str,
+        result: anyhow::Result<Vec<PathBuf>>,
+    ) {
+        match result {
+            Ok(saved_files) => {
+                for file_path in saved_files {
+                    self.print_message(&format!(
+                        "Saved {} to {}",
+                        title,
+                        file_path.display()
+                    ));
+                }
+            }
+            Err(error) => {
+                error!("Failed to save {}: {}", title, error);
+
+                self.print_message(
+                    &format!("Failed to save {}: {}", title, error)
+                        .red()
+                        .to_string(),
+                );
+            }
+        }
+    }
+
+    /// Human readable messages go to stderr when stdout
+    /// is reserved for json records
+    pub fn print_message(&self, message: &str) {
+        match self.output_format {
+            OutputFormat::Text => println!("{}", message),
+            _ => eprintln!("{}", message),
+        }
+    }
+
+    pub fn print_record(&self, record: &serde_json::Value) {
+        let output = match self.output_format {
+            OutputFormat::Json => serde_json::to_string_pretty(record),
+            OutputFormat::Ndjson => serde_json::to_string(record),
+            OutputFormat::Text => return,
+        };
+
+        match output {
+            Ok(output) => println!("{}", output),
+            Err(error) => error!("Failed to serialize record: {}", error),
+        }
+    }
+
+    /// ndjson ends with summary record, json is a single document
+    /// with all job records and summary
+    pub fn print_run_records(&self) {
+        match self.output_format {
+            OutputFormat::Json => {
+                self.print_record(&map_jobs_to_run_record(&self.jobs))
+            }
+            OutputFormat::Ndjson => {
+                self.print_record(&map_jobs_to_summary_record(&self.jobs))
+            }
+            OutputFormat::Text => {}
+        }
+    }
+
+    /// Cli argument wins over configuration file,
+    /// headless mode saves failed jobs by default
+    pub fn get_save_policy(&self) -> SavePolicy {
+        match (&self.save_policy, &self.configured_save_policy) {
+            (Some(save_policy), _) | (None, Some(save_policy)) => {
+                save_policy.clone()
+            }
+            (None, None) if self.is_headless_mode => SavePolicy::Failed,
+            (None, None) => SavePolicy::None,
+        }
+    }
+
+    pub fn get_jobs_to_save(&self) -> Vec<JobDTO> {
+        match self.get_save_policy() {
+            SavePolicy::None => Vec::new(),
+            SavePolicy::Failed => self.get_failed_jobs(),
+            SavePolicy::All => self.jobs.clone(),
+        }
+    }
+
     pub fn get_failed_jobs(&self) -> Vec<JobDTO> {
         let failed_jobs: Vec<JobDTO> = self
             .jobs
@@ -242,6 +400,14 @@ impl AppState {
         for updated_job in updated_jobs {
             if self.is_headless_mode {
                 match &updated_job.status {
+                    JobStatus::Failed | JobStatus::Finished
+                        if self.output_format == OutputFormat::Ndjson =>
+                    {
+                        self.print_record(&map_job_to_record(&updated_job));
+                    }
+                    // json is printed as a single document at the end
+                    JobStatus::Failed | JobStatus::Finished
+                        if self.output_format == OutputFormat::Json => {}
                     JobStatus::Failed | JobStatus::Finished => {
                         let name = updated_job.job_name.clone();
                         let mut cells = vec![name.white()];
@@ -264,6 +430,17 @@ impl AppState {
                         }
 
                         print_table_row(cells, false);
+
+                        for request in updated_job.requests.iter() {
+                            for violation in request.violations.iter() {
+                                println!(
+                                    "  {} {}: {}",
+                                    violation.kind.get_label().red().bold(),
+                                    request.uri,
+                                    violation.message
+                                );
+                            }
+                        }
                     }
                     _ => {}
                 }
@@ -356,7 +533,14 @@ impl AppState {
         self.notification = None;
     }
 
-    pub fn on_configuration_load(&mut self, configuration: Configuration) {
+    pub fn on_configuration_load(
+        &mut self,
+        configuration: Configuration,
+        warnings: Vec<ConfigurationProblem>,
+    ) {
+        self.configured_save_policy = configuration.save.clone();
+        self.configuration_warnings = warnings;
+
         self.domains = configuration
             .domains
             .iter()
@@ -373,7 +557,9 @@ impl AppState {
 
         self.reset_jobs_state();
 
-        if self.is_headless_mode {
+        self.show_configuration_warnings();
+
+        if self.is_headless_mode && self.output_format == OutputFormat::Text {
             let table_headers = get_headers_from_domains(&self.domains)
                 .iter()
                 .map(|text| text.to_string().bold())
@@ -383,6 +569,45 @@ impl AppState {
         }
     }
 
+    /// Headless mode prints all warnings, UI shows the first of them
+    /// in a notification and their count in the top block
+    fn show_configuration_warnings(&mut self) {
+        if self.configuration_warnings.is_empty() {
+            return;
+        }
+
+        let warnings: Vec<String> = self
+            .configuration_warnings
+            .iter()
+            .map(|warning| format_problem(&self.configuration_path, warning))
+            .collect();
+
+        if self.is_headless_mode {
+            for warning in warnings.iter() {
+                self.print_message(&warning.clone().yellow().to_string());
+            }
+
+            return;
+        }
+
+        let mut body = format!(
+            "Configuration has {} warnings, run `http-diff validate` to list them:",
+            warnings.len()
+        );
+
+        for warning in warnings.iter().take(MAX_NOTIFIED_WARNINGS) {
+            body.push('\n');
+            body.push_str(warning);
+        }
+
+        self.set_notification(Notification::new(
+            NotificationId::ConfigurationWarnings,
+            &body,
+            None,
+            NotificationType::Warning,
+        ));
+    }
+
     pub fn on_load_jobs_progress_change(
         &self,
         (current, total): (usize, usize),
@@ -632,7 +857,7 @@ impl AppState {
 
         let file_path = Path::new(path);
 
-        default_config.save(file_path).map_err(|err| {
+        default_config.save(file_path, None).map_err(|err| {
             error!("Failed to save configuration file: {}", err);
             AppError::Exception("Failed to save configuration file".into())
         })?;
@@ -641,7 +866,7 @@ impl AppState {
     }
 
     pub fn save_default_config(&self) -> Option<AppAction> {
-        let path = "./configuration.json";
+        let path = self.configuration_path.as_str();
 
         let notification = match self.generate_default_config(path) {
             Ok(()) => {
@@ -1,11 +1,29 @@
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
+
+use crate::http_diff::config::SavePolicy;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct Arguments {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub run: RunArguments,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct RunArguments {
     #[arg(short, long, default_value = "./configuration.json")]
     pub configuration: String,
 
+    #[command(flatten)]
+    pub filter: FilterArguments,
+
+    #[command(flatten)]
+    pub overrides: OverrideArguments,
+
     #[arg(short, long, default_value = "./output")]
     pub output_directory: String,
 
@@ -14,4 +32,214 @@ pub struct Arguments {
 
     #[arg(long, default_value = "false")]
     pub headless: bool,
+
+    /// Save HAR archive per domain to output directory after headless run
+    #[arg(long, default_value = "false")]
+    pub export_har: bool,
+
+    /// Write JUnit XML report of headless run to this path
+    #[arg(long, value_name = "PATH")]
+    pub report_junit: Option<String>,
+
+    /// Write static HTML report with diffs of headless run to this path
+    #[arg(long, value_name = "PATH")]
+    pub report_html: Option<String>,
+
+    /// Write markdown summary of headless run for pull request comments
+    #[arg(long, value_name = "PATH")]
+    pub report_markdown: Option<String>,
+
+    /// Maximum size of markdown summary in bytes
+    #[arg(long, value_name = "BYTES", default_value = "65000")]
+    pub report_markdown_limit: usize,
+
+    /// Jobs to save once all jobs are finished.
+    /// Overrides `save` of configuration file
+    #[arg(long, value_enum, value_name = "POLICY")]
+    pub save: Option<SavePolicy>,
+
+    /// Format of headless output, ndjson prints a record per job,
+    /// json prints a single document once all jobs are finished
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub output_format: OutputFormat,
+
+    /// Print fully expanded requests as table, json or curl commands
+    /// without sending them
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "table"
+    )]
+    pub dry_run: Option<DryRunFormat>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Execute all jobs and compare responses, the default command
+    Run(RunArguments),
+    /// Check configuration file, print its errors and warnings and exit
+    Validate(ConfigurationArguments),
+    /// Print jobs generated from configuration file without sending requests
+    List(ConfigurationArguments),
+    /// Write starter configuration file
+    Init(InitArguments),
+    /// Print JSON Schema of configuration file
+    Schema(SchemaArguments),
+    /// Generate configuration file from other formats
+    #[command(subcommand)]
+    Import(ImportCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigurationArguments {
+    #[arg(short, long, default_value = "./configuration.json")]
+    pub configuration: String,
+
+    #[command(flatten)]
+    pub filter: FilterArguments,
+
+    #[command(flatten)]
+    pub overrides: OverrideArguments,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct OverrideArguments {
+    /// Profile from `profiles` of configuration file to apply
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Domain to compare instead of domains of configuration file,
+    /// can be repeated
+    #[arg(long = "domain", value_name = "URL")]
+    pub domains: Vec<String>,
+
+    /// Domain to compare in addition to configured domains,
+    /// can be repeated
+    #[arg(long = "add-domain", value_name = "URL")]
+    pub added_domains: Vec<String>,
+
+    /// Header for n-th domain starting from 1, e.g.
+    /// `domain=2:authorization:Bearer token`, can be repeated
+    #[arg(long = "header", value_name = "domain=<n>:<name>:<value>")]
+    pub headers: Vec<String>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct FilterArguments {
+    /// Only run endpoints matching this regex
+    #[arg(long, value_name = "REGEX")]
+    pub only: Option<String>,
+
+    /// Skip endpoints matching this regex
+    #[arg(long, value_name = "REGEX")]
+    pub exclude: Option<String>,
+
+    /// Only run endpoints with this tag, can be repeated
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tags: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct InitArguments {
+    /// Path to save configuration file to
+    #[arg(short, long, default_value = "./configuration.json")]
+    pub output: String,
+
+    /// Overwrite output file if it exists
+    #[arg(long, default_value = "false")]
+    pub force: bool,
+
+    /// Add `$schema` reference for editors, defaults to the published schema
+    #[arg(long, value_name = "URL", num_args = 0..=1)]
+    pub schema: Option<Option<String>>,
+}
+
+#[derive(Args, Debug)]
+pub struct SchemaArguments {
+    /// Path to save schema to instead of printing it
+    #[arg(short, long)]
+    pub output: Option<String>,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Ndjson,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug, PartialEq)]
+pub enum DryRunFormat {
+    Table,
+    Json,
+    Curl,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ImportCommand {
+    /// Generate endpoints from OpenAPI 3 document in json or yaml format
+    Openapi(ImportOpenApiArguments),
+    /// Generate endpoints from requests recorded in a HAR file
+    Har(ImportHarArguments),
+    /// Generate endpoints from a file of curl commands
+    Curl(ImportFileArguments),
+    /// Generate endpoints from Postman v2.1 collection
+    Postman(ImportFileArguments),
+}
+
+#[derive(Args, Debug)]
+pub struct ImportArguments {
+    /// Path to save generated configuration file to
+    #[arg(short, long, default_value = "./configuration.json")]
+    pub output: String,
+
+    /// Domain to compare, can be repeated. Defaults to two localhost domains
+    #[arg(short, long = "domain")]
+    pub domains: Vec<String>,
+
+    /// Overwrite output file if it exists
+    #[arg(long, default_value = "false")]
+    pub force: bool,
+
+    /// Add `$schema` reference for editors, defaults to the published schema
+    #[arg(long, value_name = "URL", num_args = 0..=1)]
+    pub schema: Option<Option<String>>,
+}
+
+#[derive(Args, Debug)]
+pub struct ImportOpenApiArguments {
+    pub file: String,
+
+    /// Also import POST, PUT, PATCH and DELETE operations with example bodies
+    #[arg(long, default_value = "false")]
+    pub include_non_get: bool,
+
+    #[command(flatten)]
+    pub import: ImportArguments,
+}
+
+#[derive(Args, Debug)]
+pub struct ImportHarArguments {
+    pub file: String,
+
+    /// Only import requests sent to this host, can be repeated
+    #[arg(long = "host")]
+    pub hosts: Vec<String>,
+
+    /// Keep cookie, authorization and x-api-key headers
+    #[arg(long, default_value = "false")]
+    pub include_credentials: bool,
+
+    #[command(flatten)]
+    pub import: ImportArguments,
+}
+
+#[derive(Args, Debug)]
+pub struct ImportFileArguments {
+    pub file: String,
+
+    #[command(flatten)]
+    pub import: ImportArguments,
 }
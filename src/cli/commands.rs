@@ -0,0 +1,359 @@
+use anyhow::Result;
+use crossterm::style::Stylize;
+use std::{fs, path::Path, sync::Arc};
+use tokio::sync::{broadcast, Semaphore};
+use url::Url;
+
+use super::arguments::{
+    Command, ConfigurationArguments, DryRunFormat, FilterArguments,
+    ImportArguments, ImportCommand, InitArguments, OverrideArguments,
+    RunArguments, SchemaArguments,
+};
+use crate::actions::AppAction;
+use crate::http_diff::{
+    config::{
+        configuration_schema, format_problem, lint_config_file,
+        load_config_from_file, Configuration, ConfigurationOverrides,
+        DomainVariant, EndpointFilter, SCHEMA_URL,
+    },
+    export::{
+        map_jobs_to_dry_run_curl, map_jobs_to_dry_run_json,
+        map_jobs_to_dry_run_table,
+    },
+    import::{
+        load_curl_commands, load_har_document, load_postman_collection,
+        map_curl_commands_to_configuration, map_har_to_configuration,
+        map_openapi_to_configuration, map_postman_to_configuration,
+        HarImportOptions,
+    },
+    job::{map_configuration_to_jobs, Job, JobDTO},
+    types::AppError,
+    validation::load_openapi_document,
+};
+
+/// Runs every command except `run`, which is handled by the main loop
+pub fn run_command(command: &Command) -> Result<()> {
+    match command {
+        Command::Run(_) => Ok(()),
+        Command::Validate(arguments) => run_validate_command(arguments),
+        Command::List(arguments) => run_list_command(arguments),
+        Command::Init(arguments) => run_init_command(arguments),
+        Command::Schema(arguments) => run_schema_command(arguments),
+        Command::Import(import_command) => run_import_command(import_command),
+    }
+}
+
+/// Prints every problem of the configuration, warnings included,
+/// and fails if any of them is an error
+fn run_validate_command(arguments: &ConfigurationArguments) -> Result<()> {
+    let (configuration, problems) = lint_config_file(
+        &arguments.configuration,
+        &parse_configuration_overrides(&arguments.overrides)?,
+    )?;
+
+    for problem in problems.iter() {
+        let message = format_problem(&arguments.configuration, problem);
+
+        match problem.is_error() {
+            true => eprintln!("{}", message.red()),
+            false => eprintln!("{}", message.yellow()),
+        }
+    }
+
+    let errors_count =
+        problems.iter().filter(|problem| problem.is_error()).count();
+
+    if errors_count > 0 {
+        return Err(AppError::ValidationError(format!(
+            "{} is invalid: {} errors, {} warnings",
+            arguments.configuration,
+            errors_count,
+            problems.len() - errors_count
+        ))
+        .into());
+    }
+
+    let jobs = map_jobs(&configuration, &arguments.filter)?;
+
+    println!(
+        "{}",
+        format!(
+            "{} is valid: {} endpoints, {} jobs, {} warnings",
+            arguments.configuration,
+            configuration.endpoints.len(),
+            jobs.len(),
+            problems.len()
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
+fn run_list_command(arguments: &ConfigurationArguments) -> Result<()> {
+    let (configuration, jobs) = load_jobs(
+        &arguments.configuration,
+        &arguments.filter,
+        &arguments.overrides,
+    )?;
+
+    for job in jobs.iter() {
+        let http_method = job
+            .requests
+            .first()
+            .map(|request| format!("{:?}", request.http_method))
+            .unwrap_or_default();
+
+        println!("{} {}", http_method.bold(), job.job_name);
+
+        for request in job.requests.iter() {
+            println!("    {}", request.uri);
+        }
+    }
+
+    println!(
+        "\n{} jobs from {} endpoints",
+        jobs.len(),
+        configuration.endpoints.len()
+    );
+
+    Ok(())
+}
+
+/// Prints requests exactly as `run` would send them,
+/// including changes made by `request_builder`
+pub async fn run_dry_run(
+    arguments: &RunArguments,
+    format: &DryRunFormat,
+) -> Result<()> {
+    let (_, mut jobs) = load_jobs(
+        &arguments.configuration,
+        &arguments.filter,
+        &arguments.overrides,
+    )?;
+
+    for job in jobs.iter_mut() {
+        if let Some(command) = job.request_builder.clone() {
+            for request in job.requests.iter_mut() {
+                Job::build_request(&command, request).await?;
+            }
+        }
+    }
+
+    let jobs: Vec<JobDTO> = jobs.into_iter().map(JobDTO::from).collect();
+
+    match format {
+        DryRunFormat::Table => print!("{}", map_jobs_to_dry_run_table(&jobs)),
+        DryRunFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&map_jobs_to_dry_run_json(&jobs))?
+        ),
+        DryRunFormat::Curl => print!("{}", map_jobs_to_dry_run_curl(&jobs)),
+    }
+
+    Ok(())
+}
+
+fn run_init_command(arguments: &InitArguments) -> Result<()> {
+    let output = Path::new(&arguments.output);
+
+    if output.exists() && !arguments.force {
+        return Err(AppError::ValidationError(format!(
+            "{} already exists, use --force to overwrite it",
+            arguments.output
+        ))
+        .into());
+    }
+
+    Configuration::default()
+        .save(output, get_schema_reference(&arguments.schema))?;
+
+    println!(
+        "{}",
+        format!("Saved starter configuration to {}", arguments.output).green()
+    );
+
+    Ok(())
+}
+
+fn run_schema_command(arguments: &SchemaArguments) -> Result<()> {
+    let schema = serde_json::to_string_pretty(&configuration_schema())?;
+
+    match &arguments.output {
+        Some(output) => {
+            fs::write(output, schema + "\n")?;
+
+            println!("{}", format!("Saved schema to {}", output).green());
+        }
+        None => println!("{}", schema),
+    }
+
+    Ok(())
+}
+
+/// `--schema` without value references the published schema
+fn get_schema_reference(schema: &Option<Option<String>>) -> Option<&str> {
+    schema.as_ref().map(|schema| schema.as_deref().unwrap_or(SCHEMA_URL))
+}
+
+/// Parses configuration and expands it into jobs the same way `run` does,
+/// without sending any requests
+fn load_jobs(
+    configuration_path: &str,
+    filter: &FilterArguments,
+    overrides: &OverrideArguments,
+) -> Result<(Configuration, Vec<Job>), AppError> {
+    let (configuration, _) = load_config_from_file(
+        configuration_path,
+        &parse_configuration_overrides(overrides)?,
+    )?;
+
+    let jobs = map_jobs(&configuration, filter)?;
+
+    Ok((configuration, jobs))
+}
+
+fn map_jobs(
+    configuration: &Configuration,
+    filter: &FilterArguments,
+) -> Result<Vec<Job>, AppError> {
+    let (app_actions_sender, _) = broadcast::channel::<AppAction>(1);
+
+    map_configuration_to_jobs(
+        configuration,
+        &parse_endpoint_filter(filter)?,
+        app_actions_sender,
+        Arc::new(Semaphore::new(1)),
+        Arc::new(Semaphore::new(1)),
+    )
+}
+
+fn run_import_command(command: &ImportCommand) -> Result<()> {
+    let (configuration, arguments) = match command {
+        ImportCommand::Openapi(arguments) => {
+            let document = load_openapi_document(&arguments.file)?;
+
+            let configuration = map_openapi_to_configuration(
+                &document,
+                parse_domains(&arguments.import.domains)?,
+                arguments.include_non_get,
+            )?;
+
+            (configuration, &arguments.import)
+        }
+        ImportCommand::Har(arguments) => {
+            let document = load_har_document(&arguments.file)?;
+
+            let options = HarImportOptions {
+                hosts: arguments.hosts.clone(),
+                include_credentials: arguments.include_credentials,
+            };
+
+            let configuration = map_har_to_configuration(
+                &document,
+                parse_domains(&arguments.import.domains)?,
+                &options,
+            )?;
+
+            (configuration, &arguments.import)
+        }
+        ImportCommand::Curl(arguments) => {
+            let commands = load_curl_commands(&arguments.file)?;
+
+            let (configuration, warnings) =
+                map_curl_commands_to_configuration(
+                    &commands,
+                    parse_domains(&arguments.import.domains)?,
+                )?;
+
+            for warning in warnings {
+                eprintln!("{}", warning.yellow());
+            }
+
+            (configuration, &arguments.import)
+        }
+        ImportCommand::Postman(arguments) => {
+            let collection = load_postman_collection(&arguments.file)?;
+
+            let configuration = map_postman_to_configuration(
+                &collection,
+                parse_domains(&arguments.import.domains)?,
+            )?;
+
+            (configuration, &arguments.import)
+        }
+    };
+
+    save_imported_configuration(&configuration, arguments)
+}
+
+pub fn parse_endpoint_filter(
+    arguments: &FilterArguments,
+) -> Result<EndpointFilter, AppError> {
+    EndpointFilter::new(
+        arguments.only.as_deref(),
+        arguments.exclude.as_deref(),
+        arguments.tags.clone(),
+    )
+}
+
+pub fn parse_configuration_overrides(
+    arguments: &OverrideArguments,
+) -> Result<ConfigurationOverrides, AppError> {
+    ConfigurationOverrides::new(
+        arguments.profile.clone(),
+        &arguments.domains,
+        &arguments.added_domains,
+        &arguments.headers,
+    )
+}
+
+fn parse_domains(domains: &[String]) -> Result<Vec<DomainVariant>, AppError> {
+    if domains.is_empty() {
+        return Ok(vec![
+            DomainVariant::Url(Url::parse("http://localhost:3000").unwrap()),
+            DomainVariant::Url(Url::parse("http://localhost:3001").unwrap()),
+        ]);
+    }
+
+    domains
+        .iter()
+        .map(|domain| {
+            Url::parse(domain).map(DomainVariant::Url).map_err(|error| {
+                AppError::ValidationError(format!(
+                    "Invalid domain {}: {}",
+                    domain, error
+                ))
+            })
+        })
+        .collect()
+}
+
+fn save_imported_configuration(
+    configuration: &Configuration,
+    arguments: &ImportArguments,
+) -> Result<()> {
+    let output = Path::new(&arguments.output);
+
+    if output.exists() && !arguments.force {
+        return Err(AppError::ValidationError(format!(
+            "{} already exists, use --force to overwrite it",
+            arguments.output
+        ))
+        .into());
+    }
+
+    configuration.save(output, get_schema_reference(&arguments.schema))?;
+
+    println!(
+        "{}",
+        format!(
+            "Saved configuration with {} endpoints to {}",
+            configuration.endpoints.len(),
+            arguments.output
+        )
+        .green()
+    );
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
 pub mod arguments;
+pub mod commands;
 
-pub use arguments::Arguments;
+pub use arguments::*;
@@ -15,7 +15,9 @@ use crate::ui::notification::{
     Notification, NotificationId, NotificationType,
 };
 
-use super::config::load_config_from_file;
+use super::config::{
+    load_config_from_file, ConfigurationOverrides, EndpointFilter,
+};
 use super::job::{map_configuration_to_jobs, Job, JobDTO};
 use super::{
     types::AppError,
@@ -36,6 +38,8 @@ pub struct App {
     pub jobs_semaphore: Arc<Semaphore>,
     pub max_threads_semaphore: Arc<Semaphore>,
     pub app_actions_sender: broadcast::Sender<AppAction>,
+    pub endpoint_filter: EndpointFilter,
+    pub configuration_overrides: ConfigurationOverrides,
 }
 
 impl App {
@@ -51,6 +55,8 @@ impl App {
             max_threads_semaphore,
             jobs: Vec::new(),
             app_actions_sender,
+            endpoint_filter: EndpointFilter::default(),
+            configuration_overrides: ConfigurationOverrides::default(),
         })
     }
 
@@ -295,21 +301,33 @@ impl App {
         &mut self,
         path_to_file: &str,
     ) -> Result<()> {
-        let configuration = load_config_from_file(path_to_file)?;
+        let (configuration, warnings) = load_config_from_file(
+            path_to_file,
+            &self.configuration_overrides,
+        )?;
 
         self.jobs_semaphore =
             Arc::new(Semaphore::new(configuration.concurrent_jobs));
 
         let jobs = map_configuration_to_jobs(
             &configuration,
+            &self.endpoint_filter,
             self.app_actions_sender.clone(),
             self.jobs_semaphore.clone(),
             self.max_threads_semaphore.clone(),
         )?;
 
+        if jobs.is_empty() && !self.endpoint_filter.is_empty() {
+            return Err(AppError::ValidationError(format!(
+                "No endpoints match filter: {}",
+                self.endpoint_filter
+            ))
+            .into());
+        }
+
         let _ = self
             .app_actions_sender
-            .send(AppAction::ConfigurationLoaded(configuration));
+            .send(AppAction::ConfigurationLoaded(configuration, warnings));
 
         self.jobs = jobs;
 
@@ -334,18 +352,7 @@ impl App {
                 if let Some(command) = command {
                     let _ = self.max_threads_semaphore.acquire().await?;
 
-                    match Job::apply_request_builder_to_request(&command, &request).await {
-                        Ok(Some(request_builder_dto)) => {
-                            request.apply_request_builder_dto(request_builder_dto)
-                        }
-                        _ => {
-                            return Err(AppError::Exception(format!(
-                                "Failed to apply request builder: '{}' to request: '{}'",
-                                command.join(" "),
-                                request.uri
-                            )).into())
-                        }
-                    };
+                    Job::build_request(&command, request).await?;
                 }
             }
         }
@@ -0,0 +1,463 @@
+use glob::glob;
+use serde_json::Value;
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use super::format::ConfigurationFormat;
+use super::locations::{
+    find_field_locations, join_field, FieldLocation, FieldLocations,
+};
+use crate::http_diff::types::AppError;
+
+pub const EXTENDS_KEY: &str = "extends";
+pub const INCLUDE_KEY: &str = "include";
+
+/// Reads configuration file and resolves its `extends` and `include` keys.
+/// Every file of the graph is added to `files` as canonical path, even if
+/// it fails to parse, so the file watcher can pick up fixes. Directories
+/// of include patterns with wildcards are added too, for new matches.
+pub fn load_configuration_value(
+    file_path: &Path,
+    files: &mut Vec<PathBuf>,
+) -> Result<Value, AppError> {
+    load_configuration_with_locations(file_path, files).map(|(value, _)| value)
+}
+
+/// Same as `load_configuration_value`, also finds the file and location
+/// every field of the resulting configuration is defined at
+pub fn load_configuration_with_locations(
+    file_path: &Path,
+    files: &mut Vec<PathBuf>,
+) -> Result<(Value, FieldLocations), AppError> {
+    load_configuration_file(file_path, &mut Vec::new(), files)
+}
+
+/// `ancestors` are files extending or including `file_path`, only they
+/// make a cycle, the same file can still be reached by several paths
+fn load_configuration_file(
+    file_path: &Path,
+    ancestors: &mut Vec<PathBuf>,
+    files: &mut Vec<PathBuf>,
+) -> Result<(Value, FieldLocations), AppError> {
+    let canonical_path = canonicalize_path(file_path);
+
+    if ancestors.contains(&canonical_path) {
+        return Err(AppError::ValidationError(format!(
+            "Configuration file {} extends or includes itself",
+            file_path.display()
+        )));
+    }
+
+    add_file(files, &canonical_path);
+
+    ancestors.push(canonical_path);
+
+    let result = resolve_configuration_file(file_path, ancestors, files);
+
+    ancestors.pop();
+
+    result
+}
+
+fn resolve_configuration_file(
+    file_path: &Path,
+    ancestors: &mut Vec<PathBuf>,
+    files: &mut Vec<PathBuf>,
+) -> Result<(Value, FieldLocations), AppError> {
+    let (mut configuration, mut locations) =
+        read_configuration_file(file_path)?;
+
+    let object = configuration.as_object_mut().ok_or_else(|| {
+        AppError::FailedToParseConfig(format!(
+            "{} is not an object",
+            file_path.display()
+        ))
+    })?;
+
+    let extends = object.remove(EXTENDS_KEY);
+    let include = object.remove(INCLUDE_KEY);
+
+    let mut included_endpoints = Vec::new();
+
+    let endpoints_count =
+        object.get("endpoints").and_then(Value::as_array).map_or(0, Vec::len);
+
+    let mut included_files = Vec::new();
+
+    for pattern in get_include_patterns(include, file_path)? {
+        if let Some(directory) = get_include_directory(file_path, &pattern) {
+            add_file(files, &canonicalize_path(&directory));
+        }
+
+        for included_file in resolve_include_pattern(file_path, &pattern)? {
+            let canonical_path = canonicalize_path(&included_file);
+
+            if ancestors.contains(&canonical_path) {
+                return Err(AppError::ValidationError(format!(
+                    "Configuration file {} extends or includes itself",
+                    included_file.display()
+                )));
+            }
+
+            // endpoints of a file included twice would be duplicated
+            if included_files.contains(&canonical_path) {
+                return Err(AppError::ValidationError(format!(
+                    "Configuration file {} is included more than once",
+                    included_file.display()
+                )));
+            }
+
+            add_file(files, &canonical_path);
+
+            included_files.push(canonical_path);
+
+            let (endpoints, endpoint_locations) =
+                load_included_endpoints(&included_file)?;
+
+            locations.extend(shift_item_locations(
+                endpoint_locations,
+                "endpoints",
+                endpoints_count + included_endpoints.len(),
+            ));
+
+            included_endpoints.extend(endpoints);
+        }
+    }
+
+    if !included_endpoints.is_empty() {
+        let endpoints = object
+            .entry("endpoints")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        match endpoints {
+            Value::Array(endpoints) => endpoints.extend(included_endpoints),
+            _ => {
+                return Err(AppError::FailedToParseConfig(format!(
+                    "endpoints of {} is not a list",
+                    file_path.display()
+                )))
+            }
+        }
+    }
+
+    match extends {
+        None | Some(Value::Null) => Ok((configuration, locations)),
+        Some(Value::String(parent)) => {
+            let parent_path = resolve_relative_path(file_path, &parent);
+
+            let (mut parent, mut parent_locations) =
+                load_configuration_file(&parent_path, ancestors, files)?;
+
+            // fields of parent replaced by lists or values of child
+            // are gone, even if the child has no such nested field
+            let mut replaced_fields = Vec::new();
+
+            find_replaced_fields(&configuration, "", &mut replaced_fields);
+
+            parent_locations.retain(|field, _| {
+                !replaced_fields.iter().any(|replaced| {
+                    field.strip_prefix(replaced.as_str()).is_some_and(|rest| {
+                        rest.starts_with('[') || rest.starts_with('.')
+                    })
+                })
+            });
+
+            parent_locations.extend(locations);
+
+            merge_values(&mut parent, configuration);
+
+            Ok((parent, parent_locations))
+        }
+        Some(_) => Err(AppError::FailedToParseConfig(format!(
+            "extends of {} should be a path",
+            file_path.display()
+        ))),
+    }
+}
+
+/// Merges objects key by key, any other value of `child` replaces
+/// the value of `parent`, including lists
+pub fn merge_values(parent: &mut Value, child: Value) {
+    match (parent, child) {
+        (Value::Object(parent), Value::Object(child)) => {
+            for (key, child_value) in child {
+                match parent.get_mut(&key) {
+                    Some(parent_value) => {
+                        merge_values(parent_value, child_value)
+                    }
+                    None => {
+                        parent.insert(key, child_value);
+                    }
+                }
+            }
+        }
+        (parent, child) => *parent = child,
+    }
+}
+
+fn read_configuration_file(
+    file_path: &Path,
+) -> Result<(Value, FieldLocations), AppError> {
+    let content = fs::read_to_string(file_path).map_err(|_| {
+        AppError::FileNotFound(file_path.to_string_lossy().to_string())
+    })?;
+
+    let format = ConfigurationFormat::from_path(file_path);
+
+    let mut value = format.parse(&content).map_err(|error| match error {
+        AppError::FailedToParseConfig(message) => {
+            AppError::FailedToParseConfig(format!(
+                "{}: {}",
+                file_path.display(),
+                message
+            ))
+        }
+        error => error,
+    })?;
+
+    resolve_referenced_paths(&mut value, file_path);
+
+    let mut positions = find_field_locations(&format, &content);
+
+    let mut fields = Vec::new();
+
+    find_fields(&value, "", &mut fields);
+
+    let locations = fields
+        .into_iter()
+        .map(|field| {
+            let location = FieldLocation {
+                file_path: file_path.to_path_buf(),
+                location: positions.remove(&field),
+            };
+
+            (field, location)
+        })
+        .collect();
+
+    Ok((value, locations))
+}
+
+/// Included file is either a list of endpoints
+/// or a configuration with `endpoints`,
+/// locations of endpoints are returned as `[<index>]...`
+fn load_included_endpoints(
+    file_path: &Path,
+) -> Result<(Vec<Value>, FieldLocations), AppError> {
+    let (value, locations) = read_configuration_file(file_path)?;
+
+    match value {
+        Value::Array(endpoints) => Ok((endpoints, locations)),
+        Value::Object(mut object) => match object.remove("endpoints") {
+            Some(Value::Array(endpoints)) => Ok((
+                endpoints,
+                locations
+                    .into_iter()
+                    .filter_map(|(field, location)| {
+                        let field = field.strip_prefix("endpoints")?;
+
+                        Some((field.to_owned(), location))
+                    })
+                    .collect(),
+            )),
+            _ => Ok((Vec::new(), FieldLocations::new())),
+        },
+        _ => Err(AppError::FailedToParseConfig(format!(
+            "{} should contain a list of endpoints",
+            file_path.display()
+        ))),
+    }
+}
+
+/// Moves locations of list items `[<index>]...` to `<field>[<offset + index>]...`
+fn shift_item_locations(
+    locations: FieldLocations,
+    field: &str,
+    offset: usize,
+) -> FieldLocations {
+    locations
+        .into_iter()
+        .filter_map(|(item_field, location)| {
+            let (index, rest) =
+                item_field.strip_prefix('[')?.split_once(']')?;
+            let index: usize = index.parse().ok()?;
+
+            let item_field = format!("{}[{}]{}", field, offset + index, rest);
+
+            Some((item_field, location))
+        })
+        .collect()
+}
+
+/// Collects fields of `value` which replace the same fields of parent
+/// when merged, which are all fields except objects
+fn find_replaced_fields(value: &Value, field: &str, fields: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                find_replaced_fields(value, &join_field(field, key), fields);
+            }
+        }
+        _ => fields.push(field.to_owned()),
+    }
+}
+
+/// Collects paths of all fields and list items of `value`
+fn find_fields(value: &Value, field: &str, fields: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let key_field = join_field(field, key);
+
+                fields.push(key_field.clone());
+                find_fields(value, &key_field, fields);
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let item_field = format!("{}[{}]", field, index);
+
+                fields.push(item_field.clone());
+                find_fields(item, &item_field, fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn get_include_patterns(
+    include: Option<Value>,
+    file_path: &Path,
+) -> Result<Vec<String>, AppError> {
+    let invalid_include = || {
+        AppError::FailedToParseConfig(format!(
+            "include of {} should be a list of paths",
+            file_path.display()
+        ))
+    };
+
+    match include {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(Value::Array(patterns)) => patterns
+            .into_iter()
+            .map(|pattern| match pattern {
+                Value::String(pattern) => Ok(pattern),
+                _ => Err(invalid_include()),
+            })
+            .collect(),
+        Some(_) => Err(invalid_include()),
+    }
+}
+
+/// Expands glob pattern relative to the including file,
+/// matched files are sorted to keep order of endpoints stable
+fn resolve_include_pattern(
+    file_path: &Path,
+    pattern: &str,
+) -> Result<Vec<PathBuf>, AppError> {
+    let pattern_path = resolve_relative_path(file_path, pattern);
+
+    let paths = glob(&pattern_path.to_string_lossy()).map_err(|error| {
+        AppError::ValidationError(format!(
+            "Invalid include pattern {}: {}",
+            pattern, error
+        ))
+    })?;
+
+    let mut paths: Vec<PathBuf> =
+        paths.filter_map(Result::ok).filter(|path| path.is_file()).collect();
+
+    paths.sort();
+
+    if paths.is_empty() {
+        return Err(AppError::FileNotFound(
+            pattern_path.to_string_lossy().to_string(),
+        ));
+    }
+
+    Ok(paths)
+}
+
+/// Directory new files matching the include pattern appear in,
+/// which is the part of the pattern before the first wildcard
+fn get_include_directory(file_path: &Path, pattern: &str) -> Option<PathBuf> {
+    let pattern_path = resolve_relative_path(file_path, pattern);
+
+    let wildcard_index = pattern_path.components().position(|component| {
+        component.as_os_str().to_string_lossy().contains(['*', '?', '['])
+    })?;
+
+    let directory: PathBuf =
+        pattern_path.components().take(wildcard_index).collect();
+
+    let directory = match directory.as_os_str().is_empty() {
+        true => PathBuf::from("."),
+        false => directory,
+    };
+
+    directory.is_dir().then_some(directory)
+}
+
+/// Paths of files referenced by configuration are relative to the file
+/// that defines them, the same as `extends` and `include`
+fn resolve_referenced_paths(value: &mut Value, file_path: &Path) {
+    if let Some(openapi) = value.get_mut("openapi") {
+        resolve_path_value(openapi, file_path);
+    }
+
+    if let Some(replay_file) =
+        value.get_mut("replay").and_then(|replay| replay.get_mut("file"))
+    {
+        resolve_path_value(replay_file, file_path);
+    }
+
+    let endpoints = match value {
+        Value::Array(endpoints) => Some(endpoints),
+        Value::Object(object) => {
+            object.get_mut("endpoints").and_then(Value::as_array_mut)
+        }
+        _ => None,
+    };
+
+    for endpoint in endpoints.into_iter().flatten() {
+        if let Some(response_schema) = endpoint.get_mut("response_schema") {
+            resolve_path_value(response_schema, file_path);
+        }
+    }
+}
+
+/// Paths starting with environment variable are left to interpolation
+fn resolve_path_value(value: &mut Value, file_path: &Path) {
+    if let Value::String(path) = value {
+        if !path.starts_with("${") {
+            *path = resolve_relative_path(file_path, path)
+                .to_string_lossy()
+                .to_string();
+        }
+    }
+}
+
+fn add_file(files: &mut Vec<PathBuf>, canonical_path: &Path) {
+    if !files.iter().any(|file| file == canonical_path) {
+        files.push(canonical_path.to_path_buf());
+    }
+}
+
+/// The same file can be written as different relative paths,
+/// paths of missing files are kept as is
+pub fn canonicalize_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// `./` is left out, resolved paths are shown in problems of configuration
+fn resolve_relative_path(file_path: &Path, path: &str) -> PathBuf {
+    file_path
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(path)
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect()
+}
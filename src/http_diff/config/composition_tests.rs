@@ -0,0 +1,304 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::json;
+use uuid::Uuid;
+
+use super::composition::{load_configuration_value, merge_values};
+use crate::http_diff::types::AppError;
+
+fn create_directory() -> PathBuf {
+    let directory =
+        env::temp_dir().join(format!("http-diff-{}", Uuid::new_v4()));
+
+    fs::create_dir_all(directory.join("endpoints")).unwrap();
+
+    directory
+}
+
+fn write_file(path: &Path, content: &str) {
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+pub fn test_merges_values() {
+    let mut parent = json!({
+        "domains": ["http://domain-a.com", "http://domain-b.com"],
+        "variables": { "userId": [1], "status": ["active"] },
+        "concurrent_jobs": 20
+    });
+
+    merge_values(
+        &mut parent,
+        json!({
+            "domains": ["http://domain-c.com", "http://domain-d.com"],
+            "variables": { "userId": [2] }
+        }),
+    );
+
+    assert_eq!(
+        parent,
+        json!({
+            "domains": ["http://domain-c.com", "http://domain-d.com"],
+            "variables": { "userId": [2], "status": ["active"] },
+            "concurrent_jobs": 20
+        })
+    );
+}
+
+#[test]
+pub fn test_loads_extended_and_included_configuration() {
+    let directory = create_directory();
+
+    write_file(
+        &directory.join("base.json"),
+        r#"{
+          "domains": ["http://domain-a.com", "http://domain-b.com"],
+          "variables": { "userId": [1] },
+          "endpoints": [{ "endpoint": "/health" }]
+        }"#,
+    );
+
+    write_file(
+        &directory.join("endpoints/orders.yaml"),
+        "- endpoint: /api/v1/orders\n",
+    );
+
+    write_file(
+        &directory.join("endpoints/users.json"),
+        r#"{ "endpoints": [{ "endpoint": "/api/v1/users/<userId>" }] }"#,
+    );
+
+    let configuration_path = directory.join("configuration.json");
+
+    write_file(
+        &configuration_path,
+        r#"{
+          "extends": "./base.json",
+          "include": ["./endpoints/*"],
+          "variables": { "status": ["active"] }
+        }"#,
+    );
+
+    let mut files = Vec::new();
+
+    let actual =
+        load_configuration_value(&configuration_path, &mut files).unwrap();
+
+    assert_eq!(
+        actual,
+        json!({
+            "domains": ["http://domain-a.com", "http://domain-b.com"],
+            "variables": { "userId": [1], "status": ["active"] },
+            "endpoints": [
+                { "endpoint": "/api/v1/orders" },
+                { "endpoint": "/api/v1/users/<userId>" }
+            ]
+        })
+    );
+
+    let canonical_directory = fs::canonicalize(&directory).unwrap();
+
+    assert_eq!(
+        files,
+        vec![
+            canonical_directory.join("configuration.json"),
+            canonical_directory.join("endpoints"),
+            canonical_directory.join("endpoints/orders.yaml"),
+            canonical_directory.join("endpoints/users.json"),
+            canonical_directory.join("base.json"),
+        ]
+    );
+
+    write_file(
+        &directory.join("base.json"),
+        r#"{ "extends": "configuration.json" }"#,
+    );
+
+    assert!(load_configuration_value(&configuration_path, &mut Vec::new())
+        .is_err());
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+pub fn test_detects_cycle_through_different_relative_paths() {
+    let directory = create_directory();
+
+    let directory_name = directory.file_name().unwrap().to_str().unwrap();
+
+    write_file(
+        &directory.join("base.json"),
+        &format!(
+            r#"{{ "extends": "../{}/configuration.json" }}"#,
+            directory_name
+        ),
+    );
+
+    let configuration_path = directory.join("configuration.json");
+
+    write_file(&configuration_path, r#"{ "extends": "./base.json" }"#);
+
+    let actual =
+        load_configuration_value(&configuration_path, &mut Vec::new());
+
+    assert!(
+        matches!(&actual, Err(AppError::ValidationError(message)) if message.contains("itself")),
+        "{:?}",
+        actual
+    );
+
+    write_file(
+        &configuration_path,
+        &format!(
+            r#"{{ "include": ["./endpoints/*.json", "../{}/endpoints/a.json"] }}"#,
+            directory_name
+        ),
+    );
+
+    write_file(&directory.join("endpoints/a.json"), "[]");
+
+    let actual =
+        load_configuration_value(&configuration_path, &mut Vec::new());
+
+    assert!(
+        matches!(&actual, Err(AppError::ValidationError(message)) if message.contains("more than once")),
+        "{:?}",
+        actual
+    );
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+pub fn test_resolves_referenced_paths_against_defining_file() {
+    let directory = create_directory();
+
+    write_file(
+        &directory.join("endpoints/users.yaml"),
+        "- endpoint: /api/v1/users\n  response_schema: ./schemas/user.json\n",
+    );
+
+    let configuration_path = directory.join("configuration.json");
+
+    write_file(
+        &configuration_path,
+        r#"{
+          "include": ["./endpoints/*"],
+          "openapi": "./openapi.yaml",
+          "replay": { "file": "logs/access.log" },
+          "endpoints": [
+            { "endpoint": "/a", "response_schema": "schemas/a.json" },
+            { "endpoint": "/b", "response_schema": "${SCHEMAS}/b.json" },
+            { "endpoint": "/c", "response_schema": { "type": "object" } }
+          ]
+        }"#,
+    );
+
+    let actual =
+        load_configuration_value(&configuration_path, &mut Vec::new())
+            .unwrap();
+
+    let response_schemas: Vec<&serde_json::Value> = actual["endpoints"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|endpoint| &endpoint["response_schema"])
+        .collect();
+
+    let resolve =
+        |path: &str| json!(directory.join(path).to_string_lossy().to_string());
+
+    assert_eq!(
+        response_schemas,
+        vec![
+            &resolve("schemas/a.json"),
+            &json!("${SCHEMAS}/b.json"),
+            &json!({ "type": "object" }),
+            &resolve("endpoints/schemas/user.json"),
+        ]
+    );
+
+    assert_eq!(actual["openapi"], resolve("openapi.yaml"));
+    assert_eq!(actual["replay"]["file"], resolve("logs/access.log"));
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+pub fn test_loads_file_reached_by_several_paths() {
+    let directory = create_directory();
+
+    write_file(
+        &directory.join("endpoints/shared.json"),
+        r#"[{ "endpoint": "/health" }]"#,
+    );
+
+    write_file(
+        &directory.join("base.json"),
+        r#"{
+          "domains": ["http://domain-a.com", "http://domain-b.com"],
+          "include": ["./endpoints/shared.json"]
+        }"#,
+    );
+
+    write_file(
+        &directory.join("endpoints/users.json"),
+        r#"[{ "endpoint": "/api/users" }]"#,
+    );
+
+    let configuration_path = directory.join("configuration.json");
+
+    write_file(
+        &configuration_path,
+        r#"{
+          "extends": "./base.json",
+          "include": ["./endpoints/shared.json", "./endpoints/users.json"]
+        }"#,
+    );
+
+    let mut files = Vec::new();
+
+    let actual =
+        load_configuration_value(&configuration_path, &mut files).unwrap();
+
+    assert_eq!(
+        actual["endpoints"],
+        json!([{ "endpoint": "/health" }, { "endpoint": "/api/users" }])
+    );
+
+    assert_eq!(files.len(), 4);
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+pub fn test_collects_directories_of_include_patterns() {
+    let directory = create_directory();
+
+    let configuration_path = directory.join("configuration.json");
+
+    write_file(
+        &configuration_path,
+        r#"{ "include": ["./endpoints/*.json"] }"#,
+    );
+
+    let mut files = Vec::new();
+
+    let actual = load_configuration_value(&configuration_path, &mut files);
+
+    assert!(matches!(actual, Err(AppError::FileNotFound(_))));
+
+    // directory is collected even with no matches, to watch for new files
+    assert_eq!(
+        files,
+        vec![
+            fs::canonicalize(&configuration_path).unwrap(),
+            fs::canonicalize(directory.join("endpoints")).unwrap(),
+        ]
+    );
+
+    fs::remove_dir_all(directory).unwrap();
+}
@@ -1,54 +1,144 @@
 use anyhow::Result;
+use clap::ValueEnum;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use serde_json::{json, to_string_pretty, Value};
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{Read, Write},
-    path::Path,
-};
+use serde_json::{json, Value};
+use std::{collections::HashMap, fs::File, io::Write, path::Path};
 use url::Url;
 
+use super::composition::load_configuration_with_locations;
+use super::format::ConfigurationFormat;
+use super::interpolation::{interpolate_configuration, load_env_file};
+use super::lint::{
+    deserialize_configuration, format_problems, lint_configuration,
+    locate_problems, ConfigurationProblem,
+};
+use super::overrides::ConfigurationOverrides;
 use crate::http_diff::types::{
     AppError, HeaderValue, HeadersMap, HttpMethod, PathVariable,
-    PathVariableValue, VariableGenerator, VariablesMap,
+    PathVariableValue, ResponseExpectation, ResponseSchema, VariableGenerator,
+    VariablesMap,
 };
+use crate::http_diff::utils::get_placeholders_from_string;
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
 pub struct EndpointConfiguration {
+    /// path with `<placeholders>` filled from variables
     pub endpoint: String,
+    /// values of placeholders, take precedence over global variables
     pub variables: Option<VariablesMap>,
     pub http_method: Option<HttpMethod>,
+    /// headers sent to every domain, replace domain headers
     pub headers: Option<HeadersMap>,
     pub body: Option<Value>,
+    /// command that receives every response as JSON on stdin
+    /// and prints the response to compare
     pub response_processor: Option<Vec<String>>,
+    /// command that receives every request as JSON on stdin
+    /// and prints the request to send
     pub request_builder: Option<Vec<String>>,
+    pub expect: Option<ResponseExpectation>,
+    /// JSON Schema or path to it, every response body is validated against
+    pub response_schema: Option<ResponseSchema>,
+    /// tags selected with `--tag` option
+    pub tags: Option<Vec<String>>,
 }
 
-fn default_concurrent_jobs() -> usize {
+pub fn default_concurrent_jobs() -> usize {
     20
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
 pub struct UrlWithOptionalHeaders {
+    // plain string in schema, as the url can be set from `${VAR}`
+    #[schemars(with = "String")]
     pub domain: Url,
     pub headers: Option<HeadersMap>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(untagged)]
 pub enum DomainVariant {
-    Url(Url),
+    Url(#[schemars(with = "String")] Url),
     UrlWithHeaders(UrlWithOptionalHeaders),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+impl DomainVariant {
+    /// Sets header sent to this domain, replacing header with the same name
+    pub fn insert_header(&mut self, name: String, value: HeaderValue) {
+        if let DomainVariant::Url(url) = self {
+            *self = DomainVariant::UrlWithHeaders(UrlWithOptionalHeaders {
+                domain: url.clone(),
+                headers: None,
+            });
+        }
+
+        if let DomainVariant::UrlWithHeaders(domain) = self {
+            domain
+                .headers
+                .get_or_insert_with(HashMap::new)
+                .insert(name, value);
+        }
+    }
+}
+
+/// Overrides of configuration selected with `--profile` option
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct ConfigurationProfile {
+    pub domains: Option<Vec<DomainVariant>>,
+    pub variables: Option<VariablesMap>,
+    /// headers sent to every domain
+    pub headers: Option<HeadersMap>,
+    pub concurrent_jobs: Option<usize>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReplayFormat {
+    Combined,
+    Jsonl,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct ReplayConfiguration {
+    pub file: String,
+    pub format: Option<ReplayFormat>,
+    pub sample: Option<usize>,
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub dedupe: bool,
+    #[serde(default)]
+    pub include_mutating: bool,
+}
+
+/// Which jobs are saved to output directory once all jobs are finished
+#[derive(
+    Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema, ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum SavePolicy {
+    None,
+    Failed,
+    All,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
 pub struct Configuration {
+    /// domains whose responses are compared, at least 2
     pub domains: Vec<DomainVariant>,
+    #[serde(default)]
     pub endpoints: Vec<EndpointConfiguration>,
+    /// values of placeholders shared by all endpoints
     pub variables: Option<VariablesMap>,
     #[serde(default = "default_concurrent_jobs")]
     pub concurrent_jobs: usize,
+    /// path to OpenAPI 3 document responses are validated against
+    pub openapi: Option<String>,
+    /// requests replayed from access log
+    pub replay: Option<ReplayConfiguration>,
+    pub save: Option<SavePolicy>,
+    /// named overrides selected with `--profile` option
+    pub profiles: Option<HashMap<String, ConfigurationProfile>>,
 }
 
 impl Configuration {
@@ -109,6 +199,9 @@ impl Configuration {
                         "python3".to_owned(),
                         "script.py".to_owned(),
                     ]),
+                    expect: None,
+                    response_schema: None,
+                    tags: None,
                 },
                 EndpointConfiguration {
                     endpoint: "/api/v1/users/<userId>?skip=<skip>".to_string(),
@@ -121,6 +214,9 @@ impl Configuration {
                         "del(.headers.auth)".to_owned(),
                     ]),
                     request_builder: None,
+                    expect: None,
+                    response_schema: None,
+                    tags: None,
                 },
                 EndpointConfiguration {
                     endpoint: "/api/v1/users".to_string(),
@@ -133,31 +229,77 @@ impl Configuration {
                         "del(.headers.auth, .body.id)".to_owned(),
                     ]),
                     request_builder: None,
+                    expect: None,
+                    response_schema: None,
+                    tags: None,
                 },
             ],
             variables: None,
             concurrent_jobs: default_concurrent_jobs(),
+            openapi: None,
+            replay: None,
+            save: None,
+            profiles: None,
         }
     }
 
-    pub fn validate(&self) -> Result<(), AppError> {
-        if self.domains.len() < 2 {
-            return Err(AppError::ValidationError(
-                "Minimum 2 domains required".to_string(),
-            ));
+    /// Replaces `domains` and `concurrent_jobs` with values of the profile,
+    /// variables and headers of the profile are merged into configured ones
+    pub fn apply_profile(&mut self, name: &str) -> Result<(), AppError> {
+        let profile = self
+            .profiles
+            .as_ref()
+            .and_then(|profiles| profiles.get(name))
+            .cloned()
+            .ok_or_else(|| {
+                let mut names: Vec<&String> = self
+                    .profiles
+                    .iter()
+                    .flatten()
+                    .map(|(name, _)| name)
+                    .collect();
+
+                names.sort();
+
+                AppError::ValidationError(format!(
+                    "Profile {} is not found, available profiles: {:?}",
+                    name, names
+                ))
+            })?;
+
+        if let Some(domains) = profile.domains {
+            self.domains = domains;
         }
 
-        if self.endpoints.is_empty() {
-            return Err(AppError::ValidationError(
-                "No endpoints were specified".to_string(),
-            ));
+        if let Some(variables) = profile.variables {
+            self.variables.get_or_insert_with(HashMap::new).extend(variables);
+        }
+
+        for (name, value) in profile.headers.iter().flatten() {
+            for domain in self.domains.iter_mut() {
+                domain.insert_header(name.clone(), value.clone());
+            }
+        }
+
+        if let Some(concurrent_jobs) = profile.concurrent_jobs {
+            self.concurrent_jobs = concurrent_jobs;
         }
 
         Ok(())
     }
 
-    pub fn save(&self, file_path: &Path) -> Result<()> {
-        let stringified_config = to_string_pretty(self)?;
+    /// Saves configuration in the format matching extension of `file_path`,
+    /// `schema` is written first as `$schema` reference for editors
+    pub fn save(&self, file_path: &Path, schema: Option<&str>) -> Result<()> {
+        let format = ConfigurationFormat::from_path(file_path);
+
+        let stringified_config = match schema {
+            Some(schema) => format.serialize(&ConfigurationWithSchema {
+                schema,
+                configuration: self,
+            })?,
+            None => format.serialize(self)?,
+        };
 
         let mut file = File::create(&file_path)?;
 
@@ -167,23 +309,94 @@ impl Configuration {
     }
 }
 
+#[derive(Serialize)]
+struct ConfigurationWithSchema<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'a str,
+    #[serde(flatten)]
+    configuration: &'a Configuration,
+}
+
+/// Loads configuration file, fails with all errors found in it
+/// Global and endpoint variables used by placeholders of the endpoint,
+/// endpoint variables take precedence over global ones
+pub fn get_endpoint_variables(
+    global_variables: &Option<VariablesMap>,
+    endpoint_config: &EndpointConfiguration,
+) -> VariablesMap {
+    let placeholders = get_placeholders_from_string(&endpoint_config.endpoint);
+
+    let mut endpoint_variable_lookup: VariablesMap = HashMap::new();
+
+    if let Some(global_optional_variables) = global_variables {
+        endpoint_variable_lookup.extend(global_optional_variables.clone());
+    }
+
+    if let Some(endpoint_optional_variables) = &endpoint_config.variables {
+        endpoint_variable_lookup.extend(endpoint_optional_variables.clone());
+    }
+
+    endpoint_variable_lookup.retain(|key, _| placeholders.contains(key));
+
+    endpoint_variable_lookup
+}
+
+/// Loads configuration file, fails if any of its problems is an error,
+/// otherwise returns warnings along with the configuration
 pub fn load_config_from_file(
     file_path: &str,
-) -> Result<Configuration, AppError> {
-    let mut file = File::open(file_path)
-        .map_err(|_| AppError::FileNotFound(file_path.to_string()))?;
+    overrides: &ConfigurationOverrides,
+) -> Result<(Configuration, Vec<ConfigurationProblem>), AppError> {
+    let (configuration, problems) = lint_config_file(file_path, overrides)?;
+
+    if problems.iter().any(ConfigurationProblem::is_error) {
+        return Err(AppError::ValidationError(format!(
+            "Configuration is invalid:\n{}",
+            format_problems(file_path, &problems)
+        )));
+    }
 
-    let mut buffer = String::new();
+    Ok((configuration, problems))
+}
+
+/// Loads configuration file and finds its problems, warnings included.
+/// Fails only if the file can't be read or deserialized.
+pub fn lint_config_file(
+    file_path: &str,
+    overrides: &ConfigurationOverrides,
+) -> Result<(Configuration, Vec<ConfigurationProblem>), AppError> {
+    let (mut configuration_value, locations) =
+        load_configuration_with_locations(
+            Path::new(file_path),
+            &mut Vec::new(),
+        )?;
+
+    interpolate_configuration(
+        &mut configuration_value,
+        &load_env_file(Path::new(file_path))?,
+    )?;
+
+    let mut configuration = deserialize_configuration(configuration_value)
+        .map_err(|mut problems| {
+            locate_problems(&mut problems, &locations);
+
+            AppError::FailedToParseConfig(format!(
+                "Configuration is invalid:\n{}",
+                format_problems(file_path, &problems)
+            ))
+        })?;
+
+    if let Some(profile) = &overrides.profile {
+        configuration.apply_profile(profile)?;
+    }
 
-    file.read_to_string(&mut buffer)
-        .map_err(|error| AppError::FailedToParseConfig(error.to_string()))?;
+    overrides.apply(&mut configuration)?;
 
-    let configuration: Configuration = serde_json::from_str(&buffer)
-        .map_err(|error| AppError::FailedToParseConfig(error.to_string()))?;
+    let mut problems = lint_configuration(&configuration);
 
-    configuration.validate()?;
+    locate_problems(&mut problems, &locations);
 
-    Ok(configuration)
+    Ok((configuration, problems))
 }
 
 pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -150,6 +150,9 @@ pub fn test_parses_config_string() {
                 body: None,
                 response_processor: None,
                 request_builder: Some(vec!["python3".to_owned(), "script.py".to_owned()]),
+                expect: None,
+                response_schema: None,
+                tags: None,
             },
             EndpointConfiguration {
                 endpoint: "/api/v1/users/<userId>".to_string(),
@@ -159,6 +162,9 @@ pub fn test_parses_config_string() {
                 body: None,
                 response_processor: None,
                 request_builder: None,
+                expect: None,
+                response_schema: None,
+                tags: None,
             },
             EndpointConfiguration {
                 endpoint: "/api/v1/products?omit_id=<productId>&include_empty=<include_empty>"
@@ -169,6 +175,9 @@ pub fn test_parses_config_string() {
                 body: None,
                 response_processor: Some(vec!["jq".to_owned(), "del(.headers.date)".to_owned()]),
                 request_builder: None,
+                expect: None,
+                response_schema: None,
+                tags: None,
             },
             EndpointConfiguration {
                 endpoint: "/api/v1/carts?status=<status>".to_string(),
@@ -178,11 +187,162 @@ pub fn test_parses_config_string() {
                 body: None,
                 response_processor: Some(vec!["jq".to_owned(), "del(.headers.date)".to_owned()]),
                 request_builder: Some(vec!["python3".to_owned(), "script.py".to_owned()]),
+                expect: None,
+                response_schema: None,
+                tags: None,
             },
         ],
         variables: Some(expected_global_variables),
         concurrent_jobs: 20,
+        openapi: None,
+        replay: None,
+        save: None,
+        profiles: None,
     };
 
     assert_eq!(actual, expected)
 }
+
+#[test]
+pub fn test_parses_endpoint_expectations() {
+    use std::collections::HashMap;
+
+    use url::Url;
+
+    use crate::http_diff::types::{
+        ResponseExpectation, StatusCodeExpectation,
+    };
+
+    let endpoint_json_string = r#"
+    [
+      {
+        "endpoint": "/api/v1/users",
+        "expect": {
+          "status": [200, 201],
+          "equivalent_status": [[200, 201], [404, 410]]
+        }
+      },
+      {
+        "endpoint": "/api/v1/orders",
+        "expect": {
+          "status": {
+            "http://domain-b.com": [404]
+          }
+        }
+      }
+    ]
+    "#;
+
+    let actual: Vec<EndpointConfiguration> =
+        serde_json::from_str(endpoint_json_string).unwrap();
+
+    let expected = vec![
+        Some(ResponseExpectation {
+            status: Some(StatusCodeExpectation::AllDomains(vec![200, 201])),
+            equivalent_status: Some(vec![vec![200, 201], vec![404, 410]]),
+        }),
+        Some(ResponseExpectation {
+            status: Some(StatusCodeExpectation::PerDomain(HashMap::from([(
+                Url::parse("http://domain-b.com").unwrap(),
+                vec![404],
+            )]))),
+            equivalent_status: None,
+        }),
+    ];
+
+    assert_eq!(
+        actual.into_iter().map(|endpoint| endpoint.expect).collect::<Vec<_>>(),
+        expected
+    )
+}
+
+#[test]
+pub fn test_parses_save_policy() {
+    use super::config::{Configuration, SavePolicy};
+    use clap::ValueEnum;
+
+    let config_json_string = r#"
+    {
+      "domains": ["http://domain-a.com", "http://domain-b.com"],
+      "endpoints": [{ "endpoint": "/health" }],
+      "save": "all"
+    }
+    "#;
+
+    let configuration: Configuration =
+        serde_json::from_str(config_json_string).unwrap();
+
+    assert_eq!(configuration.save, Some(SavePolicy::All));
+
+    assert_eq!(
+        SavePolicy::from_str("failed", false).unwrap(),
+        SavePolicy::Failed
+    );
+    assert!(SavePolicy::from_str("passed", false).is_err());
+}
+
+#[test]
+pub fn test_applies_configuration_profile() {
+    use std::collections::HashMap;
+
+    use url::Url;
+
+    use super::config::Configuration;
+
+    let config_json_string = r#"
+    {
+      "domains": [
+        "http://dev.domain-a.com",
+        { "domain": "http://dev.domain-b.com", "headers": { "x-env": "dev" } }
+      ],
+      "endpoints": [{ "endpoint": "/api/v1/users/<userId>" }],
+      "variables": { "userId": [1, 2], "status": ["active"] },
+      "profiles": {
+        "staging": {
+          "domains": [
+            "http://staging.domain-a.com",
+            "http://staging.domain-b.com"
+          ],
+          "variables": { "userId": [3] },
+          "headers": { "authorization": "staging-token" },
+          "concurrent_jobs": 2
+        }
+      }
+    }
+    "#;
+
+    let mut configuration: Configuration =
+        serde_json::from_str(config_json_string).unwrap();
+
+    assert!(configuration.apply_profile("prod").is_err());
+
+    configuration.apply_profile("staging").unwrap();
+
+    let authorization_headers = HashMap::from([(
+        "authorization".to_owned(),
+        HeaderValue::String("staging-token".to_owned()),
+    )]);
+
+    assert_eq!(
+        configuration.domains,
+        vec![
+            DomainVariant::UrlWithHeaders(UrlWithOptionalHeaders {
+                domain: Url::parse("http://staging.domain-a.com").unwrap(),
+                headers: Some(authorization_headers.clone()),
+            }),
+            DomainVariant::UrlWithHeaders(UrlWithOptionalHeaders {
+                domain: Url::parse("http://staging.domain-b.com").unwrap(),
+                headers: Some(authorization_headers),
+            }),
+        ]
+    );
+
+    let variables = configuration.variables.unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&variables["userId"]).unwrap(),
+        serde_json::json!([3])
+    );
+    assert!(variables.contains_key("status"));
+    assert_eq!(configuration.concurrent_jobs, 2);
+}
@@ -0,0 +1,83 @@
+use regex::Regex;
+use std::fmt;
+
+use super::config::EndpointConfiguration;
+use crate::http_diff::types::AppError;
+
+/// Selects endpoints to turn into jobs by `--only`, `--exclude`
+/// and `--tag` options. Patterns are matched against endpoint templates.
+#[derive(Clone, Debug, Default)]
+pub struct EndpointFilter {
+    pub only: Option<Regex>,
+    pub exclude: Option<Regex>,
+    pub tags: Vec<String>,
+}
+
+impl EndpointFilter {
+    pub fn new(
+        only: Option<&str>,
+        exclude: Option<&str>,
+        tags: Vec<String>,
+    ) -> Result<Self, AppError> {
+        Ok(EndpointFilter {
+            only: only.map(parse_regex).transpose()?,
+            exclude: exclude.map(parse_regex).transpose()?,
+            tags,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.only.is_none() && self.exclude.is_none() && self.tags.is_empty()
+    }
+
+    /// Endpoint has to match `only`, must not match `exclude`
+    /// and has to have at least one of `tags`
+    pub fn matches(&self, endpoint: &EndpointConfiguration) -> bool {
+        if let Some(only) = &self.only {
+            if !only.is_match(&endpoint.endpoint) {
+                return false;
+            }
+        }
+
+        if let Some(exclude) = &self.exclude {
+            if exclude.is_match(&endpoint.endpoint) {
+                return false;
+            }
+        }
+
+        if self.tags.is_empty() {
+            return true;
+        }
+
+        endpoint.tags.iter().flatten().any(|tag| self.tags.contains(tag))
+    }
+}
+
+impl fmt::Display for EndpointFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+
+        if let Some(only) = &self.only {
+            parts.push(format!("only {}", only));
+        }
+
+        if let Some(exclude) = &self.exclude {
+            parts.push(format!("exclude {}", exclude));
+        }
+
+        if !self.tags.is_empty() {
+            parts.push(format!("tag {}", self.tags.join(", ")));
+        }
+
+        write!(f, "{}", parts.join("; "))
+    }
+}
+
+fn parse_regex(pattern: &str) -> Result<Regex, AppError> {
+    Regex::new(pattern).map_err(|error| {
+        AppError::ValidationError(format!(
+            "Invalid endpoint filter {}: {}",
+            pattern, error
+        ))
+    })
+}
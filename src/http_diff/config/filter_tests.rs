@@ -0,0 +1,62 @@
+use super::config::EndpointConfiguration;
+use super::filter::EndpointFilter;
+
+fn create_endpoint(
+    endpoint: &str,
+    tags: Option<&[&str]>,
+) -> EndpointConfiguration {
+    EndpointConfiguration {
+        endpoint: endpoint.to_owned(),
+        variables: None,
+        http_method: None,
+        headers: None,
+        body: None,
+        response_processor: None,
+        request_builder: None,
+        expect: None,
+        response_schema: None,
+        tags: tags
+            .map(|tags| tags.iter().map(|tag| tag.to_string()).collect()),
+    }
+}
+
+#[test]
+pub fn test_filters_endpoints() {
+    let orders = create_endpoint("/api/v1/orders/<id>", Some(&["orders"]));
+    let orders_export =
+        create_endpoint("/api/v1/orders/export", Some(&["orders", "slow"]));
+    let users = create_endpoint("/api/v1/users", None);
+
+    let filter =
+        EndpointFilter::new(Some("^/api/v1/orders"), Some("export$"), vec![])
+            .unwrap();
+
+    assert!(filter.matches(&orders));
+    assert!(!filter.matches(&orders_export));
+    assert!(!filter.matches(&users));
+
+    let filter =
+        EndpointFilter::new(None, None, vec!["slow".to_owned()]).unwrap();
+
+    assert!(!filter.matches(&orders));
+    assert!(filter.matches(&orders_export));
+    assert!(!filter.matches(&users));
+
+    assert!(EndpointFilter::default().matches(&users));
+}
+
+#[test]
+pub fn test_describes_endpoint_filter() {
+    let filter = EndpointFilter::new(
+        Some("^/api/v1/orders"),
+        None,
+        vec!["orders".to_owned(), "slow".to_owned()],
+    )
+    .unwrap();
+
+    assert!(!filter.is_empty());
+    assert_eq!(filter.to_string(), "only ^/api/v1/orders; tag orders, slow");
+    assert!(EndpointFilter::default().is_empty());
+
+    assert!(EndpointFilter::new(Some("(orders"), None, vec![]).is_err());
+}
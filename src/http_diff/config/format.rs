@@ -0,0 +1,77 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+use crate::http_diff::types::AppError;
+
+/// Format of configuration file, chosen by its extension.
+/// Files with unknown extension are treated as JSON.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigurationFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl ConfigurationFormat {
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_lowercase());
+
+        match extension.as_deref() {
+            Some("yaml") | Some("yml") => ConfigurationFormat::Yaml,
+            Some("toml") => ConfigurationFormat::Toml,
+            _ => ConfigurationFormat::Json,
+        }
+    }
+
+    pub fn parse(&self, content: &str) -> Result<Value, AppError> {
+        let parsed = match self {
+            ConfigurationFormat::Json => serde_json::from_str(content)
+                .map_err(|error| error.to_string()),
+            ConfigurationFormat::Yaml => serde_yaml::from_str(content)
+                .map_err(|error| error.to_string()),
+            ConfigurationFormat::Toml => {
+                toml::from_str(content).map_err(|error| error.to_string())
+            }
+        };
+
+        parsed.map_err(AppError::FailedToParseConfig)
+    }
+
+    pub fn serialize<T: Serialize>(
+        &self,
+        value: &T,
+    ) -> anyhow::Result<String> {
+        let serialized = match self {
+            ConfigurationFormat::Json => serde_json::to_string_pretty(value)?,
+            ConfigurationFormat::Yaml => serde_yaml::to_string(value)?,
+            ConfigurationFormat::Toml => {
+                // toml has no null, so unset fields are left out
+                let value = remove_nulls(serde_json::to_value(value)?);
+
+                toml::to_string_pretty(&value)?
+            }
+        };
+
+        Ok(serialized)
+    }
+}
+
+fn remove_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, remove_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(items) => {
+            Value::Array(items.into_iter().map(remove_nulls).collect())
+        }
+        value => value,
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use super::config::Configuration;
+use super::format::ConfigurationFormat;
+
+#[test]
+pub fn test_chooses_configuration_format_by_extension() {
+    let cases = [
+        ("./configuration.json", ConfigurationFormat::Json),
+        ("./configuration.yaml", ConfigurationFormat::Yaml),
+        ("./configuration.YML", ConfigurationFormat::Yaml),
+        ("./configuration.toml", ConfigurationFormat::Toml),
+        ("./configuration", ConfigurationFormat::Json),
+    ];
+
+    for (path, expected) in cases {
+        assert_eq!(ConfigurationFormat::from_path(Path::new(path)), expected);
+    }
+}
+
+#[test]
+pub fn test_serializes_and_parses_every_configuration_format() {
+    let configuration = Configuration::default();
+
+    for format in [
+        ConfigurationFormat::Json,
+        ConfigurationFormat::Yaml,
+        ConfigurationFormat::Toml,
+    ] {
+        let serialized = format.serialize(&configuration).unwrap();
+
+        let parsed: Configuration =
+            serde_json::from_value(format.parse(&serialized).unwrap())
+                .unwrap();
+
+        assert_eq!(parsed, configuration, "{:?}", format);
+    }
+}
+
+#[test]
+pub fn test_parses_yaml_and_toml_configuration() {
+    let yaml = r#"
+# domains to compare
+domains:
+  - http://domain-a.com
+  - domain: http://domain-b.com
+    headers:
+      cookie: auth=test
+endpoints:
+  - endpoint: /health
+"#;
+
+    let toml = r#"
+# domains to compare
+domains = [
+  "http://domain-a.com",
+  { domain = "http://domain-b.com", headers = { cookie = "auth=test" } },
+]
+
+[[endpoints]]
+endpoint = "/health"
+"#;
+
+    let from_yaml: Configuration =
+        serde_json::from_value(ConfigurationFormat::Yaml.parse(yaml).unwrap())
+            .unwrap();
+
+    let from_toml: Configuration =
+        serde_json::from_value(ConfigurationFormat::Toml.parse(toml).unwrap())
+            .unwrap();
+
+    assert_eq!(from_yaml, from_toml);
+    assert_eq!(from_yaml.endpoints[0].endpoint, "/health");
+
+    assert!(ConfigurationFormat::Toml.parse("domains = [").is_err());
+}
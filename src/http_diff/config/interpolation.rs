@@ -0,0 +1,153 @@
+use serde_json::Value;
+use std::{collections::HashMap, env, fs, path::Path};
+
+use crate::http_diff::types::AppError;
+
+pub const ENV_FILE_NAME: &str = ".env";
+
+/// Replaces `${VAR}` and `${VAR:-default}` in every string value
+/// of parsed configuration. Variables are looked up in the environment
+/// first and then in `env_file_variables`. `$${` is kept as `${`.
+pub fn interpolate_configuration(
+    configuration: &mut Value,
+    env_file_variables: &HashMap<String, String>,
+) -> Result<(), AppError> {
+    let lookup = |name: &str| {
+        env::var(name).ok().or_else(|| env_file_variables.get(name).cloned())
+    };
+
+    interpolate_value(configuration, "", &lookup)
+}
+
+fn interpolate_value(
+    value: &mut Value,
+    field: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<(), AppError> {
+    match value {
+        Value::String(text) => {
+            *text = interpolate_string(text, field, lookup)?;
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                interpolate_value(
+                    item,
+                    &format!("{}[{}]", field, index),
+                    lookup,
+                )?;
+            }
+        }
+        Value::Object(object) => {
+            for (key, item) in object.iter_mut() {
+                let field = match field.is_empty() {
+                    true => key.clone(),
+                    false => format!("{}.{}", field, key),
+                };
+
+                interpolate_value(item, &field, lookup)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+pub fn interpolate_string(
+    text: &str,
+    field: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<String, AppError> {
+    let mut result = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            result.push_str(&rest[..start - 1]);
+            result.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        result.push_str(&rest[..start]);
+
+        let end = rest[start..].find('}').ok_or_else(|| {
+            AppError::ValidationError(format!(
+                "Unclosed ${{ in field {}",
+                field
+            ))
+        })? + start;
+
+        let expression = &rest[start + 2..end];
+
+        let (name, default) = match expression.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expression, None),
+        };
+
+        let value = lookup(name)
+            .or_else(|| default.map(str::to_owned))
+            .ok_or_else(|| {
+                AppError::ValidationError(format!(
+                    "Environment variable {} used in field {} is not set",
+                    name, field
+                ))
+            })?;
+
+        result.push_str(&value);
+        rest = &rest[end + 1..];
+    }
+
+    result.push_str(rest);
+
+    Ok(result)
+}
+
+/// Reads `KEY=VALUE` lines of `.env` file next to the configuration file,
+/// returns no variables if there is no such file
+pub fn load_env_file(
+    configuration_path: &Path,
+) -> Result<HashMap<String, String>, AppError> {
+    let env_file_path = configuration_path
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(ENV_FILE_NAME);
+
+    if !env_file_path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content = fs::read_to_string(&env_file_path).map_err(|error| {
+        AppError::FailedToParseConfig(format!(
+            "Failed to read {}: {}",
+            env_file_path.display(),
+            error
+        ))
+    })?;
+
+    Ok(parse_env_file(&content))
+}
+
+pub fn parse_env_file(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+
+            let (name, value) = line.split_once('=')?;
+
+            let value = value.trim();
+
+            let value = ['"', '\'']
+                .iter()
+                .find_map(|quote| {
+                    value.strip_prefix(*quote)?.strip_suffix(*quote)
+                })
+                .unwrap_or(value);
+
+            Some((name.trim().to_owned(), value.to_owned()))
+        })
+        .collect()
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use super::interpolation::{
+    interpolate_configuration, interpolate_string, parse_env_file,
+};
+
+#[test]
+pub fn test_interpolates_string() {
+    let lookup = |name: &str| match name {
+        "TOKEN" => Some("secret".to_owned()),
+        _ => None,
+    };
+
+    assert_eq!(
+        interpolate_string("Bearer ${TOKEN}", "field", &lookup).unwrap(),
+        "Bearer secret"
+    );
+    assert_eq!(
+        interpolate_string("${HOST:-localhost}:${PORT:-3000}", "f", &lookup)
+            .unwrap(),
+        "localhost:3000"
+    );
+    assert_eq!(
+        interpolate_string("price $${TOKEN}", "field", &lookup).unwrap(),
+        "price ${TOKEN}"
+    );
+    assert_eq!(
+        interpolate_string("no variables", "field", &lookup).unwrap(),
+        "no variables"
+    );
+    assert!(interpolate_string("${TOKEN", "field", &lookup).is_err());
+}
+
+#[test]
+pub fn test_interpolates_configuration() {
+    let env_file_variables = parse_env_file(
+        "# tokens\n\
+         HTTP_DIFF_TEST_TOKEN=\"secret token\"\n\
+         export HTTP_DIFF_TEST_HOST=domain-b.com\n",
+    );
+
+    assert_eq!(
+        env_file_variables,
+        HashMap::from([
+            ("HTTP_DIFF_TEST_TOKEN".to_owned(), "secret token".to_owned()),
+            ("HTTP_DIFF_TEST_HOST".to_owned(), "domain-b.com".to_owned()),
+        ])
+    );
+
+    let mut configuration = json!({
+        "domains": [
+            "http://domain-a.com",
+            {
+                "domain": "http://${HTTP_DIFF_TEST_HOST}",
+                "headers": { "authorization": "${HTTP_DIFF_TEST_TOKEN}" }
+            }
+        ],
+        "concurrent_jobs": 5
+    });
+
+    interpolate_configuration(&mut configuration, &env_file_variables)
+        .unwrap();
+
+    assert_eq!(
+        configuration,
+        json!({
+            "domains": [
+                "http://domain-a.com",
+                {
+                    "domain": "http://domain-b.com",
+                    "headers": { "authorization": "secret token" }
+                }
+            ],
+            "concurrent_jobs": 5
+        })
+    );
+
+    let mut configuration = json!({
+        "domains": ["http://domain-a.com", "${HTTP_DIFF_TEST_MISSING}"]
+    });
+
+    let error = interpolate_configuration(&mut configuration, &HashMap::new())
+        .unwrap_err();
+
+    assert!(error.to_string().contains("domains[1]"));
+    assert!(error.to_string().contains("HTTP_DIFF_TEST_MISSING"));
+}
@@ -0,0 +1,501 @@
+use reqwest::header::{HeaderName, HeaderValue as ReqwestHeaderValue};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::{collections::HashMap, fmt};
+
+use super::config::{
+    get_endpoint_variables, Configuration, DomainVariant,
+    EndpointConfiguration,
+};
+use super::locations::{FieldLocation, FieldLocations};
+use crate::http_diff::types::{
+    HeaderValue, HeadersMap, HttpMethod, PathVariableValue,
+};
+use crate::http_diff::utils::{
+    flatten_variables_map, format_endpoint_with_variables,
+    get_placeholders_from_string,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProblemSeverity {
+    /// configuration can't be run
+    Error,
+    /// configuration runs, but likely not as intended
+    Warning,
+}
+
+impl fmt::Display for ProblemSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProblemSeverity::Error => write!(f, "error"),
+            ProblemSeverity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// Problem of configuration, `field` is a path like
+/// `endpoints[1].headers.accept`, empty for the whole configuration
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigurationProblem {
+    pub severity: ProblemSeverity,
+    pub field: String,
+    pub message: String,
+    pub location: Option<FieldLocation>,
+}
+
+impl ConfigurationProblem {
+    pub fn error(field: &str, message: String) -> Self {
+        ConfigurationProblem {
+            severity: ProblemSeverity::Error,
+            field: field.to_owned(),
+            message,
+            location: None,
+        }
+    }
+
+    pub fn warning(field: &str, message: String) -> Self {
+        ConfigurationProblem {
+            severity: ProblemSeverity::Warning,
+            field: field.to_owned(),
+            message,
+            location: None,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == ProblemSeverity::Error
+    }
+}
+
+impl fmt::Display for ConfigurationProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "{}: ", location)?;
+        }
+
+        write!(f, "{}: ", self.severity)?;
+
+        if !self.field.is_empty() {
+            write!(f, "{}: ", self.field)?;
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Formats problem as `<file>:<line>:<column>: <severity>: <field>: <message>`,
+/// `file_path` is used for problems without location
+pub fn format_problem(
+    file_path: &str,
+    problem: &ConfigurationProblem,
+) -> String {
+    match problem.location {
+        Some(_) => problem.to_string(),
+        None => format!("{}: {}", file_path, problem),
+    }
+}
+
+pub fn format_problems(
+    file_path: &str,
+    problems: &[ConfigurationProblem],
+) -> String {
+    problems
+        .iter()
+        .map(|problem| format_problem(file_path, problem))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Sets locations of problems whose fields are found in the configuration
+/// files and sorts problems by them. Fields that come from profiles or
+/// command line overrides are left without location.
+pub fn locate_problems(
+    problems: &mut [ConfigurationProblem],
+    locations: &FieldLocations,
+) {
+    for problem in problems.iter_mut() {
+        problem.location = locations.get(&problem.field).cloned();
+    }
+
+    problems.sort_by_key(|problem| {
+        let file_path =
+            problem.location.as_ref().map(|location| &location.file_path);
+
+        let position = problem
+            .location
+            .as_ref()
+            .and_then(|location| location.location)
+            .map_or((usize::MAX, 0), |location| {
+                (location.line, location.column)
+            });
+
+        (file_path.is_none(), file_path.cloned(), position)
+    });
+}
+
+/// Deserializes configuration, on failure every invalid field is reported
+/// instead of the first one serde stops at
+pub fn deserialize_configuration(
+    value: Value,
+) -> Result<Configuration, Vec<ConfigurationProblem>> {
+    let error = match serde_json::from_value::<Configuration>(value.clone()) {
+        Ok(configuration) => return Ok(configuration),
+        Err(error) => error,
+    };
+
+    let mut problems = Vec::new();
+
+    if let Value::Object(object) = &value {
+        for (key, item) in object.iter() {
+            match (key.as_str(), item) {
+                ("domains", Value::Array(domains)) => {
+                    for (index, domain) in domains.iter().enumerate() {
+                        find_invalid_fields::<DomainVariant>(
+                            domain,
+                            &Value::Null,
+                            &format!("domains[{}]", index),
+                            &mut problems,
+                        );
+                    }
+                }
+                ("endpoints", Value::Array(endpoints)) => {
+                    for (index, endpoint) in endpoints.iter().enumerate() {
+                        find_invalid_fields::<EndpointConfiguration>(
+                            endpoint,
+                            &json!({ "endpoint": "/" }),
+                            &format!("endpoints[{}]", index),
+                            &mut problems,
+                        );
+                    }
+                }
+                _ => {
+                    let mut partial = json!({ "domains": [] });
+
+                    partial[key] = item.clone();
+
+                    if let Err(error) =
+                        serde_json::from_value::<Configuration>(partial)
+                    {
+                        problems.push(ConfigurationProblem::error(
+                            key,
+                            error.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    if problems.is_empty() {
+        problems.push(ConfigurationProblem::error("", error.to_string()));
+    }
+
+    Err(problems)
+}
+
+/// Reports every field of `value` that fails to deserialize when it is set
+/// on top of `required` fields, or the whole value if no field fails alone
+fn find_invalid_fields<T: DeserializeOwned>(
+    value: &Value,
+    required: &Value,
+    field: &str,
+    problems: &mut Vec<ConfigurationProblem>,
+) {
+    let error = match serde_json::from_value::<T>(value.clone()) {
+        Ok(_) => return,
+        Err(error) => error,
+    };
+
+    let found_problems = problems.len();
+
+    if let (Value::Object(object), Value::Object(_)) = (value, required) {
+        for (key, item) in object.iter() {
+            let mut partial = required.clone();
+
+            partial[key] = item.clone();
+
+            if let Err(error) = serde_json::from_value::<T>(partial) {
+                problems.push(ConfigurationProblem::error(
+                    &format!("{}.{}", field, key),
+                    error.to_string(),
+                ));
+            }
+        }
+    }
+
+    if problems.len() == found_problems {
+        problems.push(ConfigurationProblem::error(field, error.to_string()));
+    }
+}
+
+/// Finds all problems of deserialized configuration at once
+pub fn lint_configuration(
+    configuration: &Configuration,
+) -> Vec<ConfigurationProblem> {
+    let mut problems = Vec::new();
+
+    if configuration.domains.len() < 2 {
+        problems.push(ConfigurationProblem::error(
+            "domains",
+            "Minimum 2 domains required".to_owned(),
+        ));
+    }
+
+    if configuration.endpoints.is_empty() && configuration.replay.is_none() {
+        problems.push(ConfigurationProblem::error(
+            "endpoints",
+            "No endpoints were specified".to_owned(),
+        ));
+    }
+
+    lint_domains(&configuration.domains, &mut problems);
+
+    for (index, endpoint_config) in configuration.endpoints.iter().enumerate()
+    {
+        lint_endpoint(
+            configuration,
+            endpoint_config,
+            &format!("endpoints[{}]", index),
+            &mut problems,
+        );
+    }
+
+    lint_global_variables(configuration, &mut problems);
+
+    lint_job_names(configuration, &mut problems);
+
+    problems
+}
+
+fn lint_domains(
+    domains: &[DomainVariant],
+    problems: &mut Vec<ConfigurationProblem>,
+) {
+    for (index, domain) in domains.iter().enumerate() {
+        let field = format!("domains[{}]", index);
+
+        let (url, headers) = match domain {
+            DomainVariant::Url(url) => (url, None),
+            DomainVariant::UrlWithHeaders(domain) => {
+                (&domain.domain, domain.headers.as_ref())
+            }
+        };
+
+        let duplicate = domains[..index].iter().position(|other| {
+            let other_url = match other {
+                DomainVariant::Url(url) => url,
+                DomainVariant::UrlWithHeaders(other) => &other.domain,
+            };
+
+            other_url == url
+        });
+
+        if let Some(duplicate) = duplicate {
+            problems.push(ConfigurationProblem::warning(
+                &field,
+                format!(
+                    "Domain {} is the same as domains[{}], responses will be compared with themselves",
+                    url, duplicate
+                ),
+            ));
+        }
+
+        lint_headers(headers, &format!("{}.headers", field), problems);
+    }
+}
+
+fn lint_endpoint(
+    configuration: &Configuration,
+    endpoint_config: &EndpointConfiguration,
+    field: &str,
+    problems: &mut Vec<ConfigurationProblem>,
+) {
+    let mut placeholders: Vec<String> = Vec::new();
+
+    for placeholder in get_placeholders_from_string(&endpoint_config.endpoint)
+    {
+        if !placeholders.contains(&placeholder) {
+            placeholders.push(placeholder);
+        }
+    }
+
+    let variables =
+        get_endpoint_variables(&configuration.variables, endpoint_config);
+
+    for placeholder in placeholders.iter() {
+        if !variables.contains_key(placeholder) {
+            problems.push(ConfigurationProblem::warning(
+                &format!("{}.endpoint", field),
+                format!(
+                    "Placeholder <{}> has no variable and is sent as is, add it to variables of the endpoint or global variables",
+                    placeholder
+                ),
+            ));
+        }
+    }
+
+    for name in sorted_keys(endpoint_config.variables.as_ref()) {
+        if !placeholders.contains(name) {
+            problems.push(ConfigurationProblem::warning(
+                &format!("{}.variables.{}", field, name),
+                format!(
+                    "Variable {} is not used in {}",
+                    name, endpoint_config.endpoint
+                ),
+            ));
+        }
+    }
+
+    lint_headers(
+        endpoint_config.headers.as_ref(),
+        &format!("{}.headers", field),
+        problems,
+    );
+
+    let is_get =
+        matches!(endpoint_config.http_method, None | Some(HttpMethod::GET));
+
+    if is_get && endpoint_config.body.is_some() {
+        problems.push(ConfigurationProblem::warning(
+            &format!("{}.body", field),
+            "Body is sent with GET request, many servers ignore it".to_owned(),
+        ));
+    }
+}
+
+/// Global variable is unused when every endpoint with its placeholder
+/// defines a variable with the same name
+fn lint_global_variables(
+    configuration: &Configuration,
+    problems: &mut Vec<ConfigurationProblem>,
+) {
+    for name in sorted_keys(configuration.variables.as_ref()) {
+        let is_used = configuration.endpoints.iter().any(|endpoint_config| {
+            let has_own_variable = endpoint_config
+                .variables
+                .as_ref()
+                .is_some_and(|variables| variables.contains_key(name));
+
+            !has_own_variable
+                && get_placeholders_from_string(&endpoint_config.endpoint)
+                    .contains(name)
+        });
+
+        if !is_used {
+            problems.push(ConfigurationProblem::warning(
+                &format!("variables.{}", name),
+                format!("Variable {} is not used by any endpoint", name),
+            ));
+        }
+    }
+}
+
+/// Requests are sent with reqwest, which can't send header names and values
+/// with characters outside of visible ASCII
+fn lint_headers(
+    headers: Option<&HeadersMap>,
+    field: &str,
+    problems: &mut Vec<ConfigurationProblem>,
+) {
+    for name in sorted_keys(headers) {
+        let header_field = format!("{}.{}", field, name);
+
+        if HeaderName::from_bytes(name.as_bytes()).is_err() {
+            problems.push(ConfigurationProblem::error(
+                &header_field,
+                format!("Invalid header name {:?}", name),
+            ));
+        }
+
+        if let Some(HeaderValue::String(value)) =
+            headers.and_then(|headers| headers.get(name))
+        {
+            if ReqwestHeaderValue::from_str(value).is_err() {
+                problems.push(ConfigurationProblem::error(
+                    &header_field,
+                    format!(
+                        "Invalid value of header {}, control and non-ASCII characters are not allowed",
+                        name
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Job names identify jobs in the UI and in saved output, so jobs
+/// with the same name can't be told apart
+fn lint_job_names(
+    configuration: &Configuration,
+    problems: &mut Vec<ConfigurationProblem>,
+) {
+    let mut job_endpoints: HashMap<String, usize> = HashMap::new();
+
+    for (index, endpoint_config) in configuration.endpoints.iter().enumerate()
+    {
+        for job_name in get_job_names(configuration, endpoint_config) {
+            match job_endpoints.get(&job_name) {
+                Some(other_index) => {
+                    problems.push(ConfigurationProblem::warning(
+                        &format!("endpoints[{}].endpoint", index),
+                        format!(
+                            "Job {} is already generated by endpoints[{}]",
+                            job_name, other_index
+                        ),
+                    ));
+
+                    break;
+                }
+                None => {
+                    job_endpoints.insert(job_name, index);
+                }
+            }
+        }
+    }
+}
+
+/// Names of jobs generated for the endpoint, combinations with
+/// generated values are skipped as they are unique
+fn get_job_names(
+    configuration: &Configuration,
+    endpoint_config: &EndpointConfiguration,
+) -> Vec<String> {
+    let placeholders = get_placeholders_from_string(&endpoint_config.endpoint);
+
+    let variables =
+        get_endpoint_variables(&configuration.variables, endpoint_config);
+
+    let placeholders_with_variables: Vec<&String> = placeholders
+        .iter()
+        .filter(|placeholder| variables.contains_key(*placeholder))
+        .collect();
+
+    if placeholders_with_variables.is_empty() {
+        return vec![endpoint_config.endpoint.clone()];
+    }
+
+    flatten_variables_map(variables)
+        .iter()
+        .filter(|combination| {
+            !combination
+                .values()
+                .any(|value| matches!(value, PathVariableValue::Generator(_)))
+        })
+        .map(|combination| {
+            format_endpoint_with_variables(
+                &endpoint_config.endpoint,
+                combination,
+                &placeholders_with_variables,
+            )
+        })
+        .collect()
+}
+
+fn sorted_keys<T>(map: Option<&HashMap<String, T>>) -> Vec<&String> {
+    let mut keys: Vec<&String> =
+        map.iter().flat_map(|map| map.keys()).collect();
+
+    keys.sort();
+
+    keys
+}
@@ -0,0 +1,268 @@
+use serde_json::json;
+use std::{env, fs};
+use url::Url;
+use uuid::Uuid;
+
+use super::config::{lint_config_file, Configuration, DomainVariant};
+use super::lint::{
+    deserialize_configuration, format_problems, lint_configuration,
+    ConfigurationProblem, ProblemSeverity,
+};
+use super::overrides::ConfigurationOverrides;
+use crate::http_diff::import::{
+    map_openapi_to_configuration, map_postman_to_configuration,
+};
+
+fn lint(value: serde_json::Value) -> Vec<(ProblemSeverity, String)> {
+    let configuration = deserialize_configuration(value).unwrap();
+
+    lint_configuration(&configuration)
+        .into_iter()
+        .map(|problem| (problem.severity, problem.field))
+        .collect()
+}
+
+#[test]
+pub fn test_default_configuration_has_no_problems() {
+    assert_eq!(lint_configuration(&Configuration::default()), vec![]);
+}
+
+#[test]
+pub fn test_lints_configuration() {
+    let problems = lint(json!({
+        "domains": [
+            "http://domain-a.com",
+            {
+                "domain": "http://domain-a.com",
+                "headers": { "x-test": "line\nbreak", "bad name": "a" }
+            }
+        ],
+        "endpoints": [
+            { "endpoint": "/users/<userId>/orders/<orderId>" },
+            {
+                "endpoint": "/health",
+                "body": { "a": 1 },
+                "variables": { "status": ["active"] }
+            },
+            { "endpoint": "/health", "http_method": "POST", "body": {} },
+            {
+                "endpoint": "/users/<userId>",
+                "variables": { "userId": [1, 2] }
+            }
+        ],
+        "variables": { "userId": [1], "unused": "UUID" }
+    }));
+
+    assert_eq!(
+        problems,
+        vec![
+            (ProblemSeverity::Warning, "domains[1]".to_owned()),
+            (ProblemSeverity::Error, "domains[1].headers.bad name".to_owned()),
+            (ProblemSeverity::Error, "domains[1].headers.x-test".to_owned()),
+            (ProblemSeverity::Warning, "endpoints[0].endpoint".to_owned()),
+            (
+                ProblemSeverity::Warning,
+                "endpoints[1].variables.status".to_owned()
+            ),
+            (ProblemSeverity::Warning, "endpoints[1].body".to_owned()),
+            (ProblemSeverity::Warning, "variables.unused".to_owned()),
+            (ProblemSeverity::Warning, "endpoints[2].endpoint".to_owned()),
+        ]
+    );
+}
+
+#[test]
+pub fn test_lints_duplicate_job_names_after_substitution() {
+    let problems = lint(json!({
+        "domains": ["http://domain-a.com", "http://domain-b.com"],
+        "endpoints": [
+            { "endpoint": "/users/1" },
+            { "endpoint": "/users/<userId>" },
+            {
+                "endpoint": "/orders/<orderId>",
+                "variables": { "orderId": ["UUID", "UUID"] }
+            }
+        ],
+        "variables": { "userId": [2, 1] }
+    }));
+
+    assert_eq!(
+        problems,
+        vec![(ProblemSeverity::Warning, "endpoints[1].endpoint".to_owned())]
+    );
+}
+
+#[test]
+pub fn test_requires_domains_and_endpoints() {
+    let problems = lint(json!({ "domains": ["http://domain-a.com"] }));
+
+    assert_eq!(
+        problems,
+        vec![
+            (ProblemSeverity::Error, "domains".to_owned()),
+            (ProblemSeverity::Error, "endpoints".to_owned()),
+        ]
+    );
+}
+
+#[test]
+pub fn test_reports_every_invalid_field() {
+    let problems = deserialize_configuration(json!({
+        "domains": ["http://domain-a.com", 5],
+        "endpoints": [
+            { "endpoint": "/health", "http_method": "FETCH", "tags": "a" },
+            { "variables": {} }
+        ],
+        "concurrent_jobs": "many"
+    }))
+    .unwrap_err();
+
+    let fields: Vec<&str> =
+        problems.iter().map(|problem| problem.field.as_str()).collect();
+
+    assert_eq!(
+        fields,
+        vec![
+            "concurrent_jobs",
+            "domains[1]",
+            "endpoints[0].http_method",
+            "endpoints[0].tags",
+            "endpoints[1]",
+        ]
+    );
+
+    assert!(problems.iter().all(ConfigurationProblem::is_error));
+    assert_eq!(problems[4].message, "missing field `endpoint`");
+}
+
+#[test]
+pub fn test_reports_missing_domains() {
+    let problems =
+        deserialize_configuration(json!({ "endpoints": [] })).unwrap_err();
+
+    assert_eq!(
+        problems,
+        vec![ConfigurationProblem::error(
+            "",
+            "missing field `domains`".to_owned()
+        )]
+    );
+}
+
+#[test]
+pub fn test_imported_configurations_have_no_errors() {
+    let domains = vec![
+        DomainVariant::Url(Url::parse("http://domain-a.com").unwrap()),
+        DomainVariant::Url(Url::parse("http://domain-b.com").unwrap()),
+    ];
+
+    let openapi = map_openapi_to_configuration(
+        &json!({
+            "openapi": "3.0.3",
+            "paths": {
+                "/users/{userId}": {
+                    "get": {
+                        "parameters": [{
+                            "name": "userId",
+                            "in": "path",
+                            "required": true,
+                            "schema": { "type": "integer" }
+                        }]
+                    }
+                }
+            }
+        }),
+        domains.clone(),
+        false,
+    )
+    .unwrap();
+
+    let postman = map_postman_to_configuration(
+        &json!({
+            "info": { "name": "users" },
+            "variable": [{ "key": "status", "value": "" }],
+            "item": [{
+                "name": "get user",
+                "request": {
+                    "method": "GET",
+                    "url": "https://api.example.com/users/:id?status={{status}}"
+                }
+            }]
+        }),
+        domains,
+    )
+    .unwrap();
+
+    for configuration in [openapi, postman] {
+        let problems = lint_configuration(&configuration);
+
+        assert!(!problems.is_empty());
+        assert!(
+            !problems.iter().any(ConfigurationProblem::is_error),
+            "{:?}",
+            problems
+        );
+    }
+}
+
+#[test]
+pub fn test_locates_problems_in_files_defining_fields() {
+    let directory =
+        env::temp_dir().join(format!("http-diff-{}", Uuid::new_v4()));
+
+    fs::create_dir_all(directory.join("endpoints")).unwrap();
+
+    fs::write(
+        directory.join("base.json"),
+        r#"{
+  "domains": ["http://domain-a.com", "http://domain-a.com"],
+  "endpoints": [{ "endpoint": "/health", "body": "replaced" }]
+}"#,
+    )
+    .unwrap();
+
+    fs::write(
+        directory.join("endpoints/users.yaml"),
+        "- endpoint: /api/v1/users\n  headers:\n    bad header: a\n",
+    )
+    .unwrap();
+
+    let configuration_path = directory.join("configuration.yaml");
+
+    fs::write(
+        &configuration_path,
+        "extends: ./base.json\ninclude: [./endpoints/*.yaml]\nendpoints:\n  - endpoint: /api/v1/orders\n    body: unexpected\n",
+    )
+    .unwrap();
+
+    let configuration_path = configuration_path.to_str().unwrap();
+
+    let (_, problems) = lint_config_file(
+        configuration_path,
+        &ConfigurationOverrides::default(),
+    )
+    .unwrap();
+
+    let directory = directory.to_str().unwrap();
+
+    assert_eq!(
+        format_problems(configuration_path, &problems),
+        [
+            format!(
+                "{}/base.json:2:38: warning: domains[1]: Domain http://domain-a.com/ is the same as domains[0], responses will be compared with themselves",
+                directory
+            ),
+            format!(
+                "{}/configuration.yaml: warning: endpoints[0].body: Body is sent with GET request, many servers ignore it",
+                directory
+            ),
+            format!(
+                "{}/endpoints/users.yaml: error: endpoints[1].headers.bad header: Invalid header name \"bad header\"",
+                directory
+            ),
+        ]
+        .join("\n")
+    );
+
+    fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,335 @@
+use std::{collections::HashMap, fmt, ops::Range, path::PathBuf};
+use toml_edit::{ImDocument, Item, Table, Value as TomlValue};
+
+use super::format::ConfigurationFormat;
+
+/// Position in configuration file, line and column start from 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SourceLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// File that defines a field and its position in the file,
+/// if the format gives one
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldLocation {
+    pub file_path: PathBuf,
+    pub location: Option<SourceLocation>,
+}
+
+impl fmt::Display for FieldLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.file_path.display())?;
+
+        match &self.location {
+            Some(location) => write!(f, ":{}", location),
+            None => Ok(()),
+        }
+    }
+}
+
+pub type FieldLocations = HashMap<String, FieldLocation>;
+
+/// Maps field paths to their location in `content` of the given format.
+/// `serde_yaml` gives no positions, so YAML fields are not located.
+pub fn find_field_locations(
+    format: &ConfigurationFormat,
+    content: &str,
+) -> HashMap<String, SourceLocation> {
+    match format {
+        ConfigurationFormat::Json => find_json_field_locations(content),
+        ConfigurationFormat::Yaml => HashMap::new(),
+        ConfigurationFormat::Toml => find_toml_field_locations(content),
+    }
+}
+
+pub fn join_field(field: &str, key: &str) -> String {
+    match field.is_empty() {
+        true => key.to_owned(),
+        false => format!("{}.{}", field, key),
+    }
+}
+
+/// Maps field paths like `endpoints[1].headers.accept` to their location
+/// in JSON `content`. Object fields point to their key, list items to
+/// the item itself. Invalid JSON gives locations found before the error.
+pub fn find_json_field_locations(
+    content: &str,
+) -> HashMap<String, SourceLocation> {
+    let mut scanner = JsonScanner {
+        chars: content.chars().collect(),
+        index: 0,
+        location: SourceLocation { line: 1, column: 1 },
+        locations: HashMap::new(),
+    };
+
+    scanner.scan_value("");
+
+    scanner.locations
+}
+
+struct JsonScanner {
+    chars: Vec<char>,
+    index: usize,
+    location: SourceLocation,
+    locations: HashMap<String, SourceLocation>,
+}
+
+impl JsonScanner {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.peek()?;
+
+        self.index += 1;
+
+        match char {
+            '\n' => {
+                self.location.line += 1;
+                self.location.column = 1;
+            }
+            _ => self.location.column += 1,
+        }
+
+        Some(char)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn scan_value(&mut self, field: &str) -> Option<()> {
+        self.skip_whitespace();
+
+        match self.peek()? {
+            '{' => self.scan_object(field),
+            '[' => self.scan_array(field),
+            '"' => self.scan_string().map(|_| ()),
+            _ => {
+                while self
+                    .peek()
+                    .is_some_and(|char| !",]} \t\r\n".contains(char))
+                {
+                    self.next();
+                }
+
+                Some(())
+            }
+        }
+    }
+
+    fn scan_object(&mut self, field: &str) -> Option<()> {
+        self.next();
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek()? {
+                '}' => {
+                    self.next();
+
+                    return Some(());
+                }
+                ',' => {
+                    self.next();
+                }
+                '"' => {
+                    let location = self.location;
+                    let key = self.scan_string()?;
+
+                    let field = join_field(field, &key);
+
+                    self.locations.insert(field.clone(), location);
+
+                    self.skip_whitespace();
+
+                    if self.next()? != ':' {
+                        return None;
+                    }
+
+                    self.scan_value(&field)?;
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn scan_array(&mut self, field: &str) -> Option<()> {
+        self.next();
+
+        let mut index = 0;
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek()? {
+                ']' => {
+                    self.next();
+
+                    return Some(());
+                }
+                ',' => {
+                    self.next();
+                }
+                _ => {
+                    let field = format!("{}[{}]", field, index);
+
+                    self.locations.insert(field.clone(), self.location);
+
+                    self.scan_value(&field)?;
+
+                    index += 1;
+                }
+            }
+        }
+    }
+
+    fn scan_string(&mut self) -> Option<String> {
+        self.next();
+
+        let mut value = String::new();
+
+        loop {
+            match self.next()? {
+                '"' => return Some(value),
+                '\\' => match self.next()? {
+                    'n' => value.push('\n'),
+                    't' => value.push('\t'),
+                    'r' => value.push('\r'),
+                    'b' => value.push('\u{8}'),
+                    'f' => value.push('\u{c}'),
+                    'u' => {
+                        let code: String =
+                            (0..4).filter_map(|_| self.next()).collect();
+
+                        value.push(
+                            u32::from_str_radix(&code, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .unwrap_or(char::REPLACEMENT_CHARACTER),
+                        );
+                    }
+                    char => value.push(char),
+                },
+                char => value.push(char),
+            }
+        }
+    }
+}
+
+/// Same as `find_json_field_locations`, but for TOML. Locations are taken
+/// from spans of `toml_edit` document, invalid TOML gives no locations.
+pub fn find_toml_field_locations(
+    content: &str,
+) -> HashMap<String, SourceLocation> {
+    let mut walker = TomlWalker {
+        line_starts: std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+            .collect(),
+        content,
+        locations: HashMap::new(),
+    };
+
+    if let Ok(document) = ImDocument::parse(content) {
+        walker.walk_table(document.as_table(), "");
+    }
+
+    walker.locations
+}
+
+struct TomlWalker<'a> {
+    content: &'a str,
+    line_starts: Vec<usize>,
+    locations: HashMap<String, SourceLocation>,
+}
+
+impl TomlWalker<'_> {
+    fn insert(&mut self, field: &str, span: Option<Range<usize>>) {
+        let Some(span) = span else {
+            return;
+        };
+
+        let line = self
+            .line_starts
+            .partition_point(|line_start| *line_start <= span.start);
+        let line_start = self.line_starts[line - 1];
+
+        let column = self
+            .content
+            .get(line_start..span.start)
+            .map_or(0, |prefix| prefix.chars().count());
+
+        self.locations.insert(
+            field.to_owned(),
+            SourceLocation { line, column: column + 1 },
+        );
+    }
+
+    fn walk_table(&mut self, table: &Table, field: &str) {
+        for (key, item) in table.iter() {
+            let field = join_field(field, key);
+
+            let span = table
+                .key(key)
+                .and_then(|key| key.span())
+                .or_else(|| item.span());
+
+            self.insert(&field, span);
+            self.walk_item(item, &field);
+        }
+    }
+
+    fn walk_item(&mut self, item: &Item, field: &str) {
+        match item {
+            Item::Table(table) => self.walk_table(table, field),
+            Item::ArrayOfTables(tables) => {
+                for (index, table) in tables.iter().enumerate() {
+                    let field = format!("{}[{}]", field, index);
+
+                    self.insert(&field, table.span());
+                    self.walk_table(table, &field);
+                }
+            }
+            Item::Value(value) => self.walk_value(value, field),
+            Item::None => {}
+        }
+    }
+
+    fn walk_value(&mut self, value: &TomlValue, field: &str) {
+        match value {
+            TomlValue::InlineTable(table) => {
+                for (key, value) in table.iter() {
+                    let field = join_field(field, key);
+
+                    let span = table
+                        .key(key)
+                        .and_then(|key| key.span())
+                        .or_else(|| value.span());
+
+                    self.insert(&field, span);
+                    self.walk_value(value, &field);
+                }
+            }
+            TomlValue::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    let field = format!("{}[{}]", field, index);
+
+                    self.insert(&field, item.span());
+                    self.walk_value(item, &field);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::locations::{
+    find_json_field_locations, find_toml_field_locations, SourceLocation,
+};
+
+#[test]
+pub fn test_finds_json_field_locations() {
+    let content = r#"{
+  "domains": ["http://domain-a.com", "http://domain-b.com"],
+  "endpoints": [
+    {
+      "endpoint": "/api/\"v1\"/users",
+      "headers": { "x-test": "a,b" }
+    }
+  ]
+}"#;
+
+    let locations = find_json_field_locations(content);
+
+    let location = |line, column| Some(SourceLocation { line, column });
+
+    assert_eq!(locations.get("domains").copied(), location(2, 3));
+    assert_eq!(locations.get("domains[1]").copied(), location(2, 38));
+    assert_eq!(locations.get("endpoints[0]").copied(), location(4, 5));
+    assert_eq!(
+        locations.get("endpoints[0].endpoint").copied(),
+        location(5, 7)
+    );
+    assert_eq!(
+        locations.get("endpoints[0].headers.x-test").copied(),
+        location(6, 20)
+    );
+    assert_eq!(locations.get("endpoints[1]"), None);
+}
+
+#[test]
+pub fn test_keeps_locations_found_before_invalid_json() {
+    let locations = find_json_field_locations(
+        "{\n  \"domains\": [1, 2],\n  \"endpoints\"",
+    );
+
+    assert_eq!(
+        locations.get("domains[1]"),
+        Some(&SourceLocation { line: 2, column: 18 })
+    );
+    assert_eq!(
+        locations.get("endpoints"),
+        Some(&SourceLocation { line: 3, column: 3 })
+    );
+}
+
+#[test]
+pub fn test_finds_toml_field_locations() {
+    let content = r#"domains = ["http://domain-a.com", "http://domain-b.com"]
+
+[[endpoints]]
+endpoint = "/api/v1/users"
+headers = { x-test = "a,b" }
+
+[[endpoints]]
+endpoint = "/health"
+
+[endpoints.variables]
+userId = [1]
+"#;
+
+    let locations = find_toml_field_locations(content);
+
+    let location = |line, column| Some(SourceLocation { line, column });
+
+    assert_eq!(locations.get("domains").copied(), location(1, 1));
+    assert_eq!(locations.get("domains[1]").copied(), location(1, 35));
+    assert_eq!(locations.get("endpoints[0]").copied(), location(3, 1));
+    assert_eq!(
+        locations.get("endpoints[0].headers.x-test").copied(),
+        location(5, 13)
+    );
+    assert_eq!(
+        locations.get("endpoints[1].variables.userId").copied(),
+        location(11, 1)
+    );
+}
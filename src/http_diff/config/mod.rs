@@ -1,5 +1,38 @@
+pub mod composition;
 pub mod config;
+pub mod filter;
+pub mod format;
+pub mod interpolation;
+pub mod lint;
+pub mod locations;
+pub mod overrides;
+pub mod schema;
+
+#[cfg(test)]
+mod composition_tests;
 #[cfg(test)]
 mod config_tests;
+#[cfg(test)]
+mod filter_tests;
+#[cfg(test)]
+mod format_tests;
+#[cfg(test)]
+mod interpolation_tests;
+#[cfg(test)]
+mod lint_tests;
+#[cfg(test)]
+mod locations_tests;
+#[cfg(test)]
+mod overrides_tests;
+#[cfg(test)]
+mod schema_tests;
 
+pub use composition::*;
 pub use config::*;
+pub use filter::*;
+pub use format::*;
+pub use interpolation::*;
+pub use lint::*;
+pub use locations::*;
+pub use overrides::*;
+pub use schema::*;
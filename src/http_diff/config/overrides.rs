@@ -0,0 +1,129 @@
+use url::Url;
+
+use super::config::{Configuration, DomainVariant};
+use crate::http_diff::types::{AppError, HeaderValue};
+
+/// Header passed as `--header domain=<n>:<name>:<value>`,
+/// `domain_index` starts from 1 as in `--header` option
+#[derive(Clone, Debug, PartialEq)]
+pub struct DomainHeader {
+    pub domain_index: usize,
+    pub name: String,
+    pub value: String,
+}
+
+/// Changes of configuration passed from the command line, applied
+/// to every loaded configuration file before it is validated
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigurationOverrides {
+    /// name of profile from `profiles`, applied before other overrides
+    pub profile: Option<String>,
+    pub domains: Vec<Url>,
+    pub added_domains: Vec<Url>,
+    pub headers: Vec<DomainHeader>,
+}
+
+impl ConfigurationOverrides {
+    pub fn new(
+        profile: Option<String>,
+        domains: &[String],
+        added_domains: &[String],
+        headers: &[String],
+    ) -> Result<Self, AppError> {
+        Ok(ConfigurationOverrides {
+            profile,
+            domains: parse_urls(domains)?,
+            added_domains: parse_urls(added_domains)?,
+            headers: headers
+                .iter()
+                .map(|header| parse_domain_header(header))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// `domains` replace domains of the configuration,
+    /// `added_domains` are appended, then headers are set
+    pub fn apply(
+        &self,
+        configuration: &mut Configuration,
+    ) -> Result<(), AppError> {
+        if !self.domains.is_empty() {
+            configuration.domains =
+                self.domains.iter().cloned().map(DomainVariant::Url).collect();
+        }
+
+        configuration.domains.extend(
+            self.added_domains.iter().cloned().map(DomainVariant::Url),
+        );
+
+        let domains_count = configuration.domains.len();
+
+        for header in self.headers.iter() {
+            let domain = configuration
+                .domains
+                .get_mut(header.domain_index - 1)
+                .ok_or_else(|| {
+                    AppError::ValidationError(format!(
+                        "Header {} is set for domain {}, but only {} domains are configured",
+                        header.name,
+                        header.domain_index,
+                        domains_count
+                    ))
+                })?;
+
+            domain.insert_header(
+                header.name.clone(),
+                HeaderValue::String(header.value.clone()),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_urls(urls: &[String]) -> Result<Vec<Url>, AppError> {
+    urls.iter()
+        .map(|url| {
+            Url::parse(url).map_err(|error| {
+                AppError::ValidationError(format!(
+                    "Invalid domain {}: {}",
+                    url, error
+                ))
+            })
+        })
+        .collect()
+}
+
+fn parse_domain_header(header: &str) -> Result<DomainHeader, AppError> {
+    let invalid_header = || {
+        AppError::ValidationError(format!(
+            "Invalid header {}, expected domain=<n>:<name>:<value>",
+            header
+        ))
+    };
+
+    let mut parts = header
+        .strip_prefix("domain=")
+        .ok_or_else(invalid_header)?
+        .splitn(3, ':');
+
+    let domain_index = parts
+        .next()
+        .and_then(|index| index.parse::<usize>().ok())
+        .filter(|index| *index > 0)
+        .ok_or_else(invalid_header)?;
+
+    let name = parts
+        .next()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(invalid_header)?;
+
+    let value = parts.next().ok_or_else(invalid_header)?;
+
+    Ok(DomainHeader {
+        domain_index,
+        name: name.to_owned(),
+        value: value.trim().to_owned(),
+    })
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use url::Url;
+
+use super::config::{Configuration, DomainVariant, UrlWithOptionalHeaders};
+use super::overrides::{ConfigurationOverrides, DomainHeader};
+use crate::http_diff::types::HeaderValue;
+
+#[test]
+pub fn test_parses_configuration_overrides() {
+    let overrides = ConfigurationOverrides::new(
+        None,
+        &["http://preview.domain-a.com".to_owned()],
+        &[],
+        &["domain=2:authorization:Bearer a:b".to_owned()],
+    )
+    .unwrap();
+
+    assert_eq!(
+        overrides.headers,
+        vec![DomainHeader {
+            domain_index: 2,
+            name: "authorization".to_owned(),
+            value: "Bearer a:b".to_owned(),
+        }]
+    );
+
+    for header in ["authorization:token", "domain=0:a:b", "domain=1:a"] {
+        assert!(ConfigurationOverrides::new(
+            None,
+            &[],
+            &[],
+            &[header.to_owned()]
+        )
+        .is_err());
+    }
+
+    assert!(ConfigurationOverrides::new(
+        None,
+        &["not a url".to_owned()],
+        &[],
+        &[]
+    )
+    .is_err());
+}
+
+#[test]
+pub fn test_applies_configuration_overrides() {
+    let mut configuration = Configuration::default();
+
+    let overrides = ConfigurationOverrides::new(
+        None,
+        &[],
+        &["http://preview.domain-a.com".to_owned()],
+        &[
+            "domain=3:authorization:token".to_owned(),
+            "domain=2:cookie:auth=preview".to_owned(),
+        ],
+    )
+    .unwrap();
+
+    overrides.apply(&mut configuration).unwrap();
+
+    assert_eq!(configuration.domains.len(), 3);
+
+    assert_eq!(
+        configuration.domains[2],
+        DomainVariant::UrlWithHeaders(UrlWithOptionalHeaders {
+            domain: Url::parse("http://preview.domain-a.com").unwrap(),
+            headers: Some(HashMap::from([(
+                "authorization".to_owned(),
+                HeaderValue::String("token".to_owned()),
+            )])),
+        })
+    );
+
+    match &configuration.domains[1] {
+        DomainVariant::UrlWithHeaders(domain) => assert_eq!(
+            domain.headers.as_ref().unwrap().get("cookie"),
+            Some(&HeaderValue::String("auth=preview".to_owned()))
+        ),
+        domain => panic!("unexpected domain {:?}", domain),
+    }
+
+    let overrides = ConfigurationOverrides::new(
+        None,
+        &["http://a.com".to_owned(), "http://b.com".to_owned()],
+        &[],
+        &["domain=3:authorization:token".to_owned()],
+    )
+    .unwrap();
+
+    assert!(overrides.apply(&mut configuration).is_err());
+}
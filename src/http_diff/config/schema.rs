@@ -0,0 +1,66 @@
+use schemars::schema_for;
+use serde_json::{json, Value};
+
+use super::composition::{EXTENDS_KEY, INCLUDE_KEY};
+use super::config::Configuration;
+
+pub const SCHEMA_KEY: &str = "$schema";
+
+/// Schema published with every release, referenced by `--schema`
+/// when no other reference is given. Points to the release tag, so
+/// configurations keep the schema of the version that wrote them.
+pub const SCHEMA_URL: &str = concat!(
+    "https://raw.githubusercontent.com/syte-ai/http-diff/",
+    env!("CARGO_PKG_VERSION"),
+    "/configuration.schema.json"
+);
+
+/// JSON Schema of configuration file, derived from `Configuration`.
+/// Keys resolved before deserialization are added to it by hand.
+pub fn configuration_schema() -> Value {
+    let mut schema = serde_json::to_value(schema_for!(Configuration))
+        .expect("configuration schema is serializable");
+
+    // files extending a parent or profiles may leave `domains` out,
+    // their presence is checked once configuration is composed
+    if let Some(required) = schema["required"].as_array_mut() {
+        required.retain(|field| field != "domains");
+    }
+
+    if schema["required"].as_array().is_some_and(Vec::is_empty) {
+        if let Some(schema) = schema.as_object_mut() {
+            schema.remove("required");
+        }
+    }
+
+    let properties = schema["properties"]
+        .as_object_mut()
+        .expect("configuration schema has properties");
+
+    properties.insert(
+        SCHEMA_KEY.to_owned(),
+        json!({
+            "description": "JSON Schema of this file",
+            "type": "string"
+        }),
+    );
+
+    properties.insert(
+        EXTENDS_KEY.to_owned(),
+        json!({
+            "description": "Path to parent configuration this file is merged into",
+            "type": "string"
+        }),
+    );
+
+    properties.insert(
+        INCLUDE_KEY.to_owned(),
+        json!({
+            "description": "Paths or glob patterns of files with endpoints",
+            "type": "array",
+            "items": { "type": "string" }
+        }),
+    );
+
+    schema
+}
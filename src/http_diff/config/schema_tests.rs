@@ -0,0 +1,152 @@
+use std::{env, fs};
+
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::config::{Configuration, EndpointConfiguration};
+use super::schema::{configuration_schema, SCHEMA_KEY};
+
+fn is_valid(instance: &Value) -> bool {
+    jsonschema::validator_for(&configuration_schema())
+        .unwrap()
+        .is_valid(instance)
+}
+
+#[test]
+pub fn test_published_schema_is_up_to_date() {
+    let published: Value = serde_json::from_str(include_str!(
+        "../../../configuration.schema.json"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        published,
+        configuration_schema(),
+        "run `cargo run -- schema -o configuration.schema.json`"
+    );
+}
+
+#[test]
+pub fn test_schema_has_every_serialized_field() {
+    let schema = configuration_schema();
+
+    let configuration =
+        serde_json::to_value(Configuration::default()).unwrap();
+
+    for key in configuration.as_object().unwrap().keys() {
+        assert!(schema["properties"].get(key).is_some(), "{}", key);
+    }
+
+    let endpoint =
+        serde_json::to_value(&Configuration::default().endpoints[0]).unwrap();
+
+    for key in endpoint.as_object().unwrap().keys() {
+        assert!(
+            schema["definitions"]["EndpointConfiguration"]["properties"]
+                .get(key)
+                .is_some(),
+            "{}",
+            key
+        );
+    }
+
+    for key in [SCHEMA_KEY, "extends", "include"] {
+        assert!(schema["properties"].get(key).is_some(), "{}", key);
+    }
+}
+
+#[test]
+pub fn test_schema_accepts_what_serde_accepts() {
+    let configurations = [
+        serde_json::to_value(Configuration::default()).unwrap(),
+        json!({
+            "$schema": "./configuration.schema.json",
+            "extends": "./base.json",
+            "include": ["./endpoints/*.json"],
+            "domains": [
+                "http://domain-a.com",
+                { "domain": "http://domain-b.com", "headers": { "x-id": 1 } }
+            ],
+            "endpoints": [
+                {
+                    "endpoint": "/users/<userId>",
+                    "http_method": "DELETE",
+                    "expect": { "status": { "http://domain-b.com/": [404] } },
+                    "response_schema": { "type": "object" }
+                }
+            ],
+            "variables": { "userId": [1, "admin", "UUID"], "page": 2 },
+            "replay": { "file": "./access.log", "format": "jsonl" },
+            "save": "failed",
+            "profiles": { "local": { "concurrent_jobs": 1 } }
+        }),
+    ];
+
+    for configuration in configurations {
+        assert!(serde_json::from_value::<Configuration>(
+            configuration.clone()
+        )
+        .is_ok());
+        assert!(is_valid(&configuration), "{}", configuration);
+    }
+
+    // domains of extended or profile files are checked after composition
+    assert!(is_valid(&json!({
+        "extends": "./base.json",
+        "endpoints": [{ "endpoint": "/health" }]
+    })));
+
+    // domains are interpolated before they are parsed as urls
+    assert!(is_valid(&json!({
+        "domains": ["${BASE_URL}", "${STAGE_URL:-http://domain-b.com}"]
+    })));
+}
+
+#[test]
+pub fn test_schema_rejects_what_serde_rejects() {
+    let endpoints = [
+        json!({ "variables": {} }),
+        json!({ "endpoint": "/", "http_method": "FETCH" }),
+        json!({ "endpoint": "/", "tags": "users" }),
+        json!({ "endpoint": "/", "variables": { "id": [true] } }),
+        json!({ "endpoint": "/", "headers": { "x-id": -1 } }),
+    ];
+
+    for endpoint in endpoints {
+        assert!(serde_json::from_value::<EndpointConfiguration>(
+            endpoint.clone()
+        )
+        .is_err());
+
+        let configuration = json!({
+            "domains": ["http://domain-a.com", "http://domain-b.com"],
+            "endpoints": [endpoint]
+        });
+
+        assert!(!is_valid(&configuration), "{}", configuration);
+    }
+
+    assert!(!is_valid(&json!({ "domains": "http://domain-a.com" })));
+    assert!(!is_valid(&json!({ "domains": [], "save": "some" })));
+}
+
+#[test]
+pub fn test_saves_schema_reference() {
+    let file_path =
+        env::temp_dir().join(format!("http-diff-{}.json", Uuid::new_v4()));
+
+    Configuration::default()
+        .save(&file_path, Some("./configuration.schema.json"))
+        .unwrap();
+
+    let content = fs::read_to_string(&file_path).unwrap();
+
+    assert!(content
+        .starts_with("{\n  \"$schema\": \"./configuration.schema.json\",\n"));
+
+    let saved: Configuration = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(saved, Configuration::default());
+
+    fs::remove_file(file_path).unwrap();
+}
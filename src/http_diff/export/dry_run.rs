@@ -0,0 +1,203 @@
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use super::super::job::JobDTO;
+use super::super::request::Request;
+use super::super::types::HeaderValue;
+
+const TABLE_COLUMNS: [&str; 4] = ["Method", "URL", "Headers", "Body"];
+
+/// Number of jobs and requests generated from one endpoint
+#[derive(Debug, PartialEq)]
+pub struct EndpointCount {
+    pub endpoint: String,
+    pub jobs: usize,
+    pub requests: usize,
+}
+
+/// Counts jobs and requests per endpoint in configuration order
+pub fn count_jobs_per_endpoint(jobs: &[JobDTO]) -> Vec<EndpointCount> {
+    let mut counts: Vec<EndpointCount> = Vec::new();
+
+    for job in jobs.iter() {
+        let index = match counts
+            .iter()
+            .position(|count| count.endpoint == job.endpoint)
+        {
+            Some(index) => index,
+            None => {
+                counts.push(EndpointCount {
+                    endpoint: job.endpoint.clone(),
+                    jobs: 0,
+                    requests: 0,
+                });
+
+                counts.len() - 1
+            }
+        };
+
+        counts[index].jobs += 1;
+        counts[index].requests += job.requests.len();
+    }
+
+    counts
+}
+
+/// Prints every request as a row of a plain text table
+/// followed by counts per endpoint
+pub fn map_jobs_to_dry_run_table(jobs: &[JobDTO]) -> String {
+    let rows: Vec<[String; 4]> = jobs
+        .iter()
+        .flat_map(|job| job.requests.iter())
+        .map(|request| {
+            [
+                format!("{:?}", request.http_method),
+                request.uri.to_string(),
+                format_headers(request),
+                request
+                    .body
+                    .as_ref()
+                    .map(|body| body.to_string())
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    let mut table = format_table(&TABLE_COLUMNS.map(String::from), &rows);
+
+    let counts: Vec<[String; 3]> = count_jobs_per_endpoint(jobs)
+        .into_iter()
+        .map(|count| {
+            [
+                count.endpoint,
+                count.jobs.to_string(),
+                count.requests.to_string(),
+            ]
+        })
+        .collect();
+
+    table.push('\n');
+    table.push_str(&format_table(
+        &["Endpoint", "Jobs", "Requests"].map(String::from),
+        &counts,
+    ));
+
+    table.push_str(&format!(
+        "\n{} jobs, {} requests\n",
+        jobs.len(),
+        rows.len()
+    ));
+
+    table
+}
+
+pub fn map_jobs_to_dry_run_json(jobs: &[JobDTO]) -> Value {
+    let requests: Vec<Value> = jobs
+        .iter()
+        .flat_map(|job| {
+            job.requests.iter().map(|request| {
+                let headers: BTreeMap<&String, String> = request
+                    .headers
+                    .iter()
+                    .flatten()
+                    .map(|(name, value)| (name, value.to_string()))
+                    .collect();
+
+                json!({
+                    "job": job.job_name,
+                    "endpoint": job.endpoint,
+                    "method": format!("{:?}", request.http_method),
+                    "uri": request.uri.as_str(),
+                    "headers": headers,
+                    "body": request.body,
+                })
+            })
+        })
+        .collect();
+
+    let endpoints: Vec<Value> = count_jobs_per_endpoint(jobs)
+        .into_iter()
+        .map(|count| {
+            json!({
+                "endpoint": count.endpoint,
+                "jobs": count.jobs,
+                "requests": count.requests,
+            })
+        })
+        .collect();
+
+    json!({
+        "total_jobs": jobs.len(),
+        "total_requests": requests.len(),
+        "endpoints": endpoints,
+        "requests": requests,
+    })
+}
+
+/// Prints curl commands of every job, counts are added as comments
+/// so the output can be piped into a shell as is
+pub fn map_jobs_to_dry_run_curl(jobs: &[JobDTO]) -> String {
+    let mut script = String::new();
+
+    for job in jobs.iter() {
+        script.push_str(&format!("# {}\n", job.job_name));
+
+        for request in job.requests.iter() {
+            script.push_str(&request.to_curl_command());
+            script.push('\n');
+        }
+
+        script.push('\n');
+    }
+
+    for count in count_jobs_per_endpoint(jobs) {
+        script.push_str(&format!(
+            "# {}: {} jobs, {} requests\n",
+            count.endpoint, count.jobs, count.requests
+        ));
+    }
+
+    script
+}
+
+fn format_headers(request: &Request) -> String {
+    let headers: BTreeMap<&String, &HeaderValue> =
+        request.headers.iter().flatten().collect();
+
+    headers
+        .iter()
+        .map(|(name, value)| format!("{}: {}", name, value))
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
+fn format_table<const N: usize>(
+    header: &[String; N],
+    rows: &[[String; N]],
+) -> String {
+    let mut widths = header.each_ref().map(|column| column.chars().count());
+
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &[String; N]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect();
+
+        format!("{}\n", cells.join("  ").trim_end())
+    };
+
+    let mut table = format_row(header);
+
+    for row in rows.iter() {
+        table.push_str(&format_row(row));
+    }
+
+    table
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use url::Url;
+
+use super::dry_run::{
+    count_jobs_per_endpoint, map_jobs_to_dry_run_json,
+    map_jobs_to_dry_run_table, EndpointCount,
+};
+use crate::http_diff::{
+    job::JobDTO,
+    request::Request,
+    types::{HeaderValue, HttpMethod, JobStatus},
+};
+
+fn create_job(job_name: &str, endpoint: &str) -> JobDTO {
+    let headers = HashMap::from([(
+        "x-user".to_owned(),
+        HeaderValue::String("test".to_owned()),
+    )]);
+
+    let requests = ["http://domain-a.com", "http://domain-b.com"]
+        .iter()
+        .map(|domain| {
+            Request::new(
+                &Url::parse(&format!("{}{}", domain, job_name)).unwrap(),
+                &HttpMethod::POST,
+                Some(headers.clone()),
+                Some(json!({ "id": 1 })),
+            )
+        })
+        .collect();
+
+    JobDTO {
+        requests,
+        status: JobStatus::Pending,
+        job_duration: None,
+        job_name: job_name.to_owned(),
+        endpoint: endpoint.to_owned(),
+    }
+}
+
+#[test]
+pub fn test_counts_jobs_per_endpoint() {
+    let jobs = vec![
+        create_job("/api/users/2", "/api/users/<id>"),
+        create_job("/health", "/health"),
+        create_job("/api/users/1", "/api/users/<id>"),
+    ];
+
+    assert_eq!(
+        count_jobs_per_endpoint(&jobs),
+        vec![
+            EndpointCount {
+                endpoint: "/api/users/<id>".to_owned(),
+                jobs: 2,
+                requests: 4,
+            },
+            EndpointCount {
+                endpoint: "/health".to_owned(),
+                jobs: 1,
+                requests: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+pub fn test_maps_jobs_to_dry_run_output() {
+    let jobs = vec![create_job("/health", "/health")];
+
+    let expected_table = "\
+Method  URL                         Headers       Body
+POST    http://domain-a.com/health  x-user: test  {\"id\":1}
+POST    http://domain-b.com/health  x-user: test  {\"id\":1}
+
+Endpoint  Jobs  Requests
+/health   1     2
+
+1 jobs, 2 requests
+";
+
+    assert_eq!(map_jobs_to_dry_run_table(&jobs), expected_table);
+
+    assert_eq!(
+        map_jobs_to_dry_run_json(&jobs),
+        json!({
+            "total_jobs": 1,
+            "total_requests": 2,
+            "endpoints": [
+                { "endpoint": "/health", "jobs": 1, "requests": 2 }
+            ],
+            "requests": [
+                {
+                    "job": "/health",
+                    "endpoint": "/health",
+                    "method": "POST",
+                    "uri": "http://domain-a.com/health",
+                    "headers": { "x-user": "test" },
+                    "body": { "id": 1 }
+                },
+                {
+                    "job": "/health",
+                    "endpoint": "/health",
+                    "method": "POST",
+                    "uri": "http://domain-b.com/health",
+                    "headers": { "x-user": "test" },
+                    "body": { "id": 1 }
+                }
+            ]
+        })
+    );
+}
@@ -0,0 +1,196 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeMap,
+    fs::{create_dir_all, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use super::super::config::{APP_NAME, APP_VERSION};
+use super::super::job::JobDTO;
+use super::super::request::{Request, ResponseVariant};
+use super::super::types::{HeaderValue, HeadersMap};
+use super::super::utils::clean_special_chars_for_filename;
+
+/// Groups finished requests of all jobs by domain origin,
+/// every domain gets its own HAR log
+pub fn map_jobs_to_har_archives(jobs: &[JobDTO]) -> BTreeMap<String, Value> {
+    let mut entries_by_domain: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+
+    for job in jobs {
+        for request in job.requests.iter() {
+            if request.response.is_none() {
+                continue;
+            }
+
+            entries_by_domain
+                .entry(request.uri.origin().ascii_serialization())
+                .or_default()
+                .push(map_request_to_har_entry(request));
+        }
+    }
+
+    entries_by_domain
+        .into_iter()
+        .map(|(domain, entries)| {
+            let archive = json!({
+                "log": {
+                    "version": "1.2",
+                    "creator": { "name": APP_NAME, "version": APP_VERSION },
+                    "entries": entries
+                }
+            });
+
+            (domain, archive)
+        })
+        .collect()
+}
+
+pub fn save_har_archives(
+    jobs: &[JobDTO],
+    directory: &Path,
+) -> Result<Vec<PathBuf>> {
+    if !directory.exists() {
+        create_dir_all(directory)?;
+    }
+
+    let mut saved_files = Vec::new();
+
+    for (domain, archive) in map_jobs_to_har_archives(jobs) {
+        let host = domain.split_once("://").map(|(_, host)| host);
+
+        let file_path = directory.join(format!(
+            "{}.har",
+            clean_special_chars_for_filename(host.unwrap_or(&domain))
+        ));
+
+        let mut file = File::create(&file_path)?;
+
+        file.write_all(serde_json::to_string_pretty(&archive)?.as_bytes())?;
+
+        saved_files.push(file_path);
+    }
+
+    Ok(saved_files)
+}
+
+pub fn map_request_to_har_entry(request: &Request) -> Value {
+    let response = match &request.response {
+        Some(response) => map_response_to_har(response),
+        None => Value::Null,
+    };
+
+    let duration = request
+        .job_duration
+        .map(|duration| duration.as_secs_f64() * 1000.0)
+        .unwrap_or(-1.0);
+
+    let started_at = request
+        .started_at
+        .map(|started_at| started_at.to_rfc3339())
+        .unwrap_or_default();
+
+    let query_string: Vec<Value> = request
+        .uri
+        .query_pairs()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect();
+
+    let mut har_request = json!({
+        "method": format!("{:?}", request.http_method),
+        "url": request.uri.as_str(),
+        "httpVersion": "HTTP/1.1",
+        "cookies": [],
+        "headers": map_headers_to_har(request.headers.as_ref()),
+        "queryString": query_string,
+        "headersSize": -1,
+        "bodySize": -1,
+    });
+
+    if let Some(body) = &request.body {
+        let text = body.to_string();
+
+        har_request["bodySize"] = json!(text.len());
+        har_request["postData"] =
+            json!({ "mimeType": "application/json", "text": text });
+    }
+
+    json!({
+        "startedDateTime": started_at,
+        "time": duration,
+        "request": har_request,
+        "response": response,
+        "cache": {},
+        "timings": { "send": 0, "wait": duration, "receive": 0 },
+    })
+}
+
+fn map_response_to_har(response: &ResponseVariant) -> Value {
+    let response = match response {
+        ResponseVariant::Success(response) => response,
+        ResponseVariant::Fail(error) => {
+            return json!({
+                "status": 0,
+                "statusText": "",
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": [],
+                "content": { "size": 0, "mimeType": "" },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": -1,
+                "_error": error,
+            })
+        }
+    };
+
+    let status_text = reqwest::StatusCode::from_u16(response.status_code)
+        .ok()
+        .and_then(|status_code| status_code.canonical_reason())
+        .unwrap_or_default();
+
+    let mime_type = match response.headers.get("content-type") {
+        Some(HeaderValue::String(content_type)) => content_type.clone(),
+        _ => String::new(),
+    };
+
+    let text = response.body.as_ref().map(Value::to_string);
+
+    let size = text.as_ref().map(String::len).unwrap_or_default();
+
+    let mut content = json!({ "size": size, "mimeType": mime_type });
+
+    if let Some(text) = text {
+        content["text"] = json!(text);
+    }
+
+    let body_size = match response.content_length {
+        Some(content_length) => content_length as i64,
+        None => -1,
+    };
+
+    json!({
+        "status": response.status_code,
+        "statusText": status_text,
+        "httpVersion": "HTTP/1.1",
+        "cookies": [],
+        "headers": map_headers_to_har(Some(&response.headers)),
+        "content": content,
+        "redirectURL": "",
+        "headersSize": -1,
+        "bodySize": body_size,
+    })
+}
+
+fn map_headers_to_har(headers: Option<&HeadersMap>) -> Vec<Value> {
+    let headers: BTreeMap<&String, &HeaderValue> =
+        headers.into_iter().flatten().collect();
+
+    headers
+        .into_iter()
+        .map(|(name, value)| {
+            json!({ "name": name, "value": value.to_string() })
+        })
+        .collect()
+}
@@ -0,0 +1,126 @@
+use std::{collections::HashMap, time::Duration};
+
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use url::Url;
+
+use super::har::{map_jobs_to_har_archives, map_request_to_har_entry};
+use crate::http_diff::{
+    job::JobDTO,
+    request::{Request, Response, ResponseVariant},
+    types::{HeaderValue, HttpMethod, JobStatus},
+};
+
+fn create_request(uri: &str, response: Option<ResponseVariant>) -> Request {
+    let mut request = Request::new(
+        &Url::parse(uri).unwrap(),
+        &HttpMethod::POST,
+        Some(HashMap::from([(
+            "x-test".to_owned(),
+            HeaderValue::String("true".to_owned()),
+        )])),
+        Some(json!({ "username": "test" })),
+    );
+
+    request.started_at =
+        Some(Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap());
+    request.job_duration = Some(Duration::from_millis(120));
+    request.response = response;
+
+    request
+}
+
+fn create_response() -> ResponseVariant {
+    ResponseVariant::Success(Response {
+        status_code: 201,
+        content_length: Some(12),
+        headers: HashMap::from([(
+            "content-type".to_owned(),
+            HeaderValue::String("application/json".to_owned()),
+        )]),
+        body: Some(json!({ "id": 1 })),
+    })
+}
+
+#[test]
+pub fn test_maps_request_to_har_entry() {
+    let request = create_request(
+        "http://domain-a.com/api/users?source=test",
+        Some(create_response()),
+    );
+
+    let actual = map_request_to_har_entry(&request);
+
+    let expected = json!({
+        "startedDateTime": "2024-01-01T10:00:00+00:00",
+        "time": 120.0,
+        "request": {
+            "method": "POST",
+            "url": "http://domain-a.com/api/users?source=test",
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": [{ "name": "x-test", "value": "true" }],
+            "queryString": [{ "name": "source", "value": "test" }],
+            "headersSize": -1,
+            "bodySize": 19,
+            "postData": {
+                "mimeType": "application/json",
+                "text": "{\"username\":\"test\"}"
+            }
+        },
+        "response": {
+            "status": 201,
+            "statusText": "Created",
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": [{ "name": "content-type", "value": "application/json" }],
+            "content": {
+                "size": 8,
+                "mimeType": "application/json",
+                "text": "{\"id\":1}"
+            },
+            "redirectURL": "",
+            "headersSize": -1,
+            "bodySize": 12
+        },
+        "cache": {},
+        "timings": { "send": 0, "wait": 120.0, "receive": 0 }
+    });
+
+    assert_eq!(actual, expected)
+}
+
+#[test]
+pub fn test_groups_har_entries_by_domain() {
+    let job = JobDTO {
+        requests: vec![
+            create_request(
+                "http://domain-a.com/api/users",
+                Some(create_response()),
+            ),
+            create_request(
+                "http://domain-b.com/api/users",
+                Some(ResponseVariant::Fail("connection refused".to_owned())),
+            ),
+            create_request("http://domain-c.com/api/users", None),
+        ],
+        status: JobStatus::Failed,
+        job_duration: None,
+        job_name: "/api/users".to_owned(),
+        endpoint: "/api/users".to_owned(),
+    };
+
+    let actual = map_jobs_to_har_archives(&[job]);
+
+    let domains: Vec<&String> = actual.keys().collect();
+
+    assert_eq!(domains, vec!["http://domain-a.com", "http://domain-b.com"]);
+
+    let failed_entry = &actual["http://domain-b.com"]["log"]["entries"][0];
+
+    assert_eq!(failed_entry["response"]["status"], json!(0));
+    assert_eq!(
+        failed_entry["response"]["_error"],
+        json!("connection refused")
+    );
+}
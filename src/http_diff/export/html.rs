@@ -0,0 +1,301 @@
+use anyhow::Result;
+use chrono::Local;
+use similar::ChangeTag;
+use std::{
+    fs::{create_dir_all, File},
+    io::Write,
+    path::Path,
+};
+
+use super::super::job::JobDTO;
+use super::super::request::Request;
+use super::super::types::JobStatus;
+use super::junit::escape_xml;
+
+const STYLES: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; margin: 24px; color: #1f2328; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #d0d7de; padding: 4px 8px; text-align: left; vertical-align: top; }
+.summary td.passed { background: #dafbe1; }
+.summary td.failed { background: #ffebe9; }
+.filters button { margin-right: 4px; }
+.filters button.active { font-weight: bold; }
+.job { margin-top: 32px; }
+.diff { width: 100%; table-layout: fixed; font-family: monospace; font-size: 12px; }
+.diff td { white-space: pre-wrap; word-break: break-all; border: none; padding: 0 8px; }
+.diff td.delete { background: #ffebe9; }
+.diff td.insert { background: #dafbe1; }
+pre { background: #f6f8fa; padding: 8px; overflow-x: auto; }
+"#;
+
+const SCRIPT: &str = r#"
+function filterJobs(status) {
+  document.querySelectorAll('[data-status]').forEach(function (element) {
+    element.style.display = status === 'all' || element.dataset.status === status ? '' : 'none';
+  });
+  document.querySelectorAll('.filters button').forEach(function (button) {
+    button.classList.toggle('active', button.dataset.filter === status);
+  });
+}
+"#;
+
+/// Builds a single static html page with summary table
+/// and side-by-side diffs of failed jobs
+pub fn map_jobs_to_html_report(jobs: &[JobDTO]) -> String {
+    let failed_jobs_count = jobs.iter().filter(|job| job.is_failed()).count();
+
+    let mut html = String::from("<!DOCTYPE html>\n<html>\n<head>\n");
+
+    html.push_str(
+        "<meta charset=\"utf-8\">\n<title>http-diff report</title>\n",
+    );
+    html.push_str(&format!("<style>{}</style>\n", STYLES));
+    html.push_str(&format!("<script>{}</script>\n", SCRIPT));
+    html.push_str("</head>\n<body>\n<h1>http-diff report</h1>\n");
+
+    html.push_str(&format!(
+        "<p>Generated at {}. {} jobs, {} failed.</p>\n",
+        Local::now().format("%Y-%m-%d %H:%M:%S"),
+        jobs.len(),
+        failed_jobs_count
+    ));
+
+    html.push_str(
+        "<div class=\"filters\">\n\
+         <button data-filter=\"all\" class=\"active\" onclick=\"filterJobs('all')\">All</button>\n\
+         <button data-filter=\"failed\" onclick=\"filterJobs('failed')\">Failed</button>\n\
+         <button data-filter=\"passed\" onclick=\"filterJobs('passed')\">Passed</button>\n\
+         </div>\n",
+    );
+
+    html.push_str(&map_jobs_to_summary_table(jobs));
+
+    for (index, job) in jobs.iter().enumerate() {
+        if job.is_failed() {
+            html.push_str(&map_failed_job_to_html(index, job));
+        }
+    }
+
+    html.push_str("</body>\n</html>\n");
+
+    html
+}
+
+pub fn save_html_report(jobs: &[JobDTO], file_path: &Path) -> Result<()> {
+    if let Some(directory) = file_path.parent() {
+        if !directory.as_os_str().is_empty() && !directory.exists() {
+            create_dir_all(directory)?;
+        }
+    }
+
+    let mut file = File::create(file_path)?;
+
+    file.write_all(map_jobs_to_html_report(jobs).as_bytes())?;
+
+    Ok(())
+}
+
+fn map_jobs_to_summary_table(jobs: &[JobDTO]) -> String {
+    let domains: Vec<String> = jobs
+        .first()
+        .map(|job| {
+            job.requests
+                .iter()
+                .map(|request| request.uri.origin().ascii_serialization())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut table = String::from(
+        "<table class=\"summary\">\n<tr><th>Job</th><th>Method</th>",
+    );
+
+    for domain in domains.iter() {
+        table.push_str(&format!("<th>{}</th>", escape_xml(domain)));
+    }
+
+    table.push_str("</tr>\n");
+
+    for (index, job) in jobs.iter().enumerate() {
+        let job_name = match job.is_failed() {
+            true => format!(
+                "<a href=\"#job-{}\">{}</a>",
+                index,
+                escape_xml(&job.job_name)
+            ),
+            false => escape_xml(&job.job_name),
+        };
+
+        let http_method = job
+            .requests
+            .first()
+            .map(|request| format!("{:?}", request.http_method))
+            .unwrap_or_default();
+
+        table.push_str(&format!(
+            "<tr data-status=\"{}\"><td>{}</td><td>{}</td>",
+            get_filter_status(&job.status),
+            job_name,
+            http_method
+        ));
+
+        for request in job.requests.iter() {
+            table.push_str(&format!(
+                "<td class=\"{}\">{}</td>",
+                get_filter_status(&request.status),
+                escape_xml(request.get_status_text().trim())
+            ));
+        }
+
+        table.push_str("</tr>\n");
+    }
+
+    table.push_str("</table>\n");
+
+    table
+}
+
+fn map_failed_job_to_html(index: usize, job: &JobDTO) -> String {
+    let mut html = format!(
+        "<section class=\"job\" id=\"job-{}\" data-status=\"failed\">\n\
+         <h2>{}</h2>\n",
+        index,
+        escape_xml(&job.job_name)
+    );
+
+    let baseline = match job.requests.first() {
+        Some(request) => request,
+        None => return html,
+    };
+
+    for request in job.requests.iter() {
+        for violation in request.violations.iter() {
+            html.push_str(&format!(
+                "<p><b>{} violation</b> for {}: {}</p>\n",
+                violation.kind.get_label(),
+                escape_xml(request.uri.as_str()),
+                escape_xml(&violation.message)
+            ));
+        }
+    }
+
+    for request in job.get_requests_with_diffs() {
+        if !request.has_diffs {
+            continue;
+        }
+
+        html.push_str(&format!(
+            "<table class=\"diff\">\n<tr><th>{}</th><th>{}</th></tr>\n",
+            escape_xml(baseline.uri.as_str()),
+            escape_xml(request.uri.as_str())
+        ));
+
+        for (left, right) in map_diffs_to_side_by_side(&request.diffs) {
+            html.push_str(&format!(
+                "<tr>{}{}</tr>\n",
+                map_diff_cell_to_html(left),
+                map_diff_cell_to_html(right)
+            ));
+        }
+
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("<details>\n<summary>Raw responses</summary>\n");
+
+    for request in job.requests.iter() {
+        html.push_str(&map_raw_response_to_html(request));
+    }
+
+    html.push_str("</details>\n</section>\n");
+
+    html
+}
+
+type DiffCell<'a> = Option<(ChangeTag, &'a str)>;
+
+/// Pairs removed and added lines into rows,
+/// unchanged lines are shown on both sides
+pub fn map_diffs_to_side_by_side(
+    diffs: &[(ChangeTag, String)],
+) -> Vec<(DiffCell<'_>, DiffCell<'_>)> {
+    let mut rows = Vec::new();
+    let mut deleted: Vec<&str> = Vec::new();
+    let mut inserted: Vec<&str> = Vec::new();
+
+    for (tag, line) in diffs {
+        let line = line.trim_end_matches('\n');
+
+        match tag {
+            ChangeTag::Delete => deleted.push(line),
+            ChangeTag::Insert => inserted.push(line),
+            ChangeTag::Equal => {
+                flush_changed_lines(&mut rows, &mut deleted, &mut inserted);
+
+                rows.push((
+                    Some((ChangeTag::Equal, line)),
+                    Some((ChangeTag::Equal, line)),
+                ));
+            }
+        }
+    }
+
+    flush_changed_lines(&mut rows, &mut deleted, &mut inserted);
+
+    rows
+}
+
+fn flush_changed_lines<'a>(
+    rows: &mut Vec<(DiffCell<'a>, DiffCell<'a>)>,
+    deleted: &mut Vec<&'a str>,
+    inserted: &mut Vec<&'a str>,
+) {
+    let rows_count = deleted.len().max(inserted.len());
+
+    for index in 0..rows_count {
+        rows.push((
+            deleted.get(index).map(|line| (ChangeTag::Delete, *line)),
+            inserted.get(index).map(|line| (ChangeTag::Insert, *line)),
+        ));
+    }
+
+    deleted.clear();
+    inserted.clear();
+}
+
+fn map_diff_cell_to_html(cell: DiffCell) -> String {
+    match cell {
+        Some((ChangeTag::Delete, line)) => {
+            format!("<td class=\"delete\">{}</td>", escape_xml(line))
+        }
+        Some((ChangeTag::Insert, line)) => {
+            format!("<td class=\"insert\">{}</td>", escape_xml(line))
+        }
+        Some((ChangeTag::Equal, line)) => {
+            format!("<td>{}</td>", escape_xml(line))
+        }
+        None => "<td></td>".to_owned(),
+    }
+}
+
+fn map_raw_response_to_html(request: &Request) -> String {
+    let response = request
+        .response
+        .as_ref()
+        .and_then(|response| serde_json::to_string_pretty(response).ok())
+        .unwrap_or_default();
+
+    format!(
+        "<h4>{}</h4>\n<pre>{}</pre>\n",
+        escape_xml(request.uri.as_str()),
+        escape_xml(&response)
+    )
+}
+
+fn get_filter_status(status: &JobStatus) -> &'static str {
+    match status {
+        JobStatus::Failed => "failed",
+        JobStatus::Finished => "passed",
+        _ => "pending",
+    }
+}
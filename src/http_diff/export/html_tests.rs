@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use similar::ChangeTag;
+use url::Url;
+
+use super::html::{map_diffs_to_side_by_side, map_jobs_to_html_report};
+use crate::http_diff::{
+    job::JobDTO,
+    request::{Request, Response, ResponseVariant},
+    types::{HttpMethod, JobStatus},
+};
+
+fn create_request(uri: &str, status: JobStatus) -> Request {
+    let mut request =
+        Request::new(&Url::parse(uri).unwrap(), &HttpMethod::GET, None, None);
+
+    request.status = status;
+    request.response = Some(ResponseVariant::Success(Response {
+        status_code: 200,
+        content_length: None,
+        headers: HashMap::new(),
+        body: None,
+    }));
+
+    request
+}
+
+#[test]
+pub fn test_maps_diffs_to_side_by_side_rows() {
+    let diffs = vec![
+        (ChangeTag::Equal, "{\n".to_owned()),
+        (ChangeTag::Delete, "  \"a\": 1,\n".to_owned()),
+        (ChangeTag::Delete, "  \"b\": 2\n".to_owned()),
+        (ChangeTag::Insert, "  \"a\": 3\n".to_owned()),
+        (ChangeTag::Equal, "}\n".to_owned()),
+    ];
+
+    let actual = map_diffs_to_side_by_side(&diffs);
+
+    let expected = vec![
+        (Some((ChangeTag::Equal, "{")), Some((ChangeTag::Equal, "{"))),
+        (
+            Some((ChangeTag::Delete, "  \"a\": 1,")),
+            Some((ChangeTag::Insert, "  \"a\": 3")),
+        ),
+        (Some((ChangeTag::Delete, "  \"b\": 2")), None),
+        (Some((ChangeTag::Equal, "}")), Some((ChangeTag::Equal, "}"))),
+    ];
+
+    assert_eq!(actual, expected)
+}
+
+#[test]
+pub fn test_maps_jobs_to_html_report() {
+    let mut failed_request =
+        create_request("http://domain-b.com/api/<users>", JobStatus::Failed);
+
+    failed_request.has_diffs = true;
+    failed_request.diffs = vec![
+        (ChangeTag::Delete, "<old>\n".to_owned()),
+        (ChangeTag::Insert, "<new>\n".to_owned()),
+    ];
+
+    let jobs = vec![
+        JobDTO {
+            requests: vec![
+                create_request(
+                    "http://domain-a.com/health",
+                    JobStatus::Finished,
+                ),
+                create_request(
+                    "http://domain-b.com/health",
+                    JobStatus::Finished,
+                ),
+            ],
+            status: JobStatus::Finished,
+            job_duration: None,
+            job_name: "/health".to_owned(),
+            endpoint: "/health".to_owned(),
+        },
+        JobDTO {
+            requests: vec![
+                create_request(
+                    "http://domain-a.com/api/<users>",
+                    JobStatus::Finished,
+                ),
+                failed_request,
+            ],
+            status: JobStatus::Failed,
+            job_duration: None,
+            job_name: "/api/<users>".to_owned(),
+            endpoint: "/api/<users>".to_owned(),
+        },
+    ];
+
+    let actual = map_jobs_to_html_report(&jobs);
+
+    assert!(actual.contains(
+        "<tr><th>Job</th><th>Method</th><th>http://domain-a.com</th><th>http://domain-b.com</th></tr>"
+    ));
+    assert!(actual.contains("<tr data-status=\"passed\"><td>/health</td>"));
+    assert!(actual.contains(
+        "<tr data-status=\"failed\"><td><a href=\"#job-1\">/api/&lt;users&gt;</a></td>"
+    ));
+    assert!(actual.contains("<section class=\"job\" id=\"job-1\""));
+    assert!(actual.contains(
+        "<tr><td class=\"delete\">&lt;old&gt;</td><td class=\"insert\">&lt;new&gt;</td></tr>"
+    ));
+    assert!(!actual.contains("id=\"job-0\""));
+}
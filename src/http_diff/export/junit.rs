@@ -0,0 +1,203 @@
+use anyhow::Result;
+use similar::ChangeTag;
+use std::{
+    collections::BTreeMap,
+    fs::{create_dir_all, File},
+    io::Write,
+    path::Path,
+    time::Duration,
+};
+
+use super::super::job::JobDTO;
+use super::super::request::{Request, ResponseVariant};
+use super::super::types::JobStatus;
+
+const MAX_DIFF_LINES: usize = 50;
+
+/// Builds JUnit XML report with a testsuite per endpoint template
+/// and a testcase per job
+pub fn map_jobs_to_junit_report(jobs: &[JobDTO]) -> String {
+    let mut jobs_by_endpoint: BTreeMap<&str, Vec<&JobDTO>> = BTreeMap::new();
+
+    for job in jobs {
+        jobs_by_endpoint.entry(&job.endpoint).or_default().push(job);
+    }
+
+    let test_suites: Vec<String> = jobs_by_endpoint
+        .iter()
+        .map(|(endpoint, jobs)| map_jobs_to_test_suite(endpoint, jobs))
+        .collect();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"http-diff\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n\
+         {}</testsuites>\n",
+        jobs.len(),
+        jobs.iter().filter(|job| job.is_failed()).count(),
+        get_total_seconds(jobs.iter()),
+        test_suites.concat()
+    )
+}
+
+pub fn save_junit_report(jobs: &[JobDTO], file_path: &Path) -> Result<()> {
+    if let Some(directory) = file_path.parent() {
+        if !directory.as_os_str().is_empty() && !directory.exists() {
+            create_dir_all(directory)?;
+        }
+    }
+
+    let mut file = File::create(file_path)?;
+
+    file.write_all(map_jobs_to_junit_report(jobs).as_bytes())?;
+
+    Ok(())
+}
+
+fn map_jobs_to_test_suite(endpoint: &str, jobs: &[&JobDTO]) -> String {
+    let test_cases: Vec<String> =
+        jobs.iter().map(|job| map_job_to_test_case(job)).collect();
+
+    format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n\
+         {}  </testsuite>\n",
+        escape_xml(endpoint),
+        jobs.len(),
+        jobs.iter().filter(|job| job.is_failed()).count(),
+        jobs.iter().filter(|job| !is_job_completed(job)).count(),
+        get_total_seconds(jobs.iter().copied()),
+        test_cases.concat()
+    )
+}
+
+fn map_job_to_test_case(job: &JobDTO) -> String {
+    let opening_tag = format!(
+        "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+        escape_xml(&job.job_name),
+        escape_xml(&job.endpoint),
+        job.job_duration.unwrap_or_default().as_secs_f64()
+    );
+
+    match job.status {
+        JobStatus::Failed => format!(
+            "{}>\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+            opening_tag,
+            escape_xml(&get_failure_message(job)),
+            escape_xml(&get_failure_details(job))
+        ),
+        JobStatus::Finished => format!("{}/>\n", opening_tag),
+        _ => format!("{}>\n      <skipped/>\n    </testcase>\n", opening_tag),
+    }
+}
+
+fn get_failure_message(job: &JobDTO) -> String {
+    let statuses: Vec<String> = job
+        .requests
+        .iter()
+        .map(|request| {
+            format!(
+                "{} {}",
+                request.uri.origin().ascii_serialization(),
+                get_response_status(request)
+            )
+        })
+        .collect();
+
+    statuses.join(", ")
+}
+
+fn get_failure_details(job: &JobDTO) -> String {
+    let mut details = String::from("status codes:\n");
+
+    for request in job.requests.iter() {
+        details.push_str(&format!(
+            "  {}: {}\n",
+            request.uri,
+            get_response_status(request)
+        ));
+    }
+
+    for request in job.requests.iter() {
+        for violation in request.violations.iter() {
+            details.push_str(&format!(
+                "\n{} violation for {}: {}\n",
+                violation.kind.get_label(),
+                request.uri,
+                violation.message
+            ));
+        }
+    }
+
+    for request in job.get_requests_with_diffs() {
+        let changed_lines: Vec<String> = request
+            .diffs
+            .iter()
+            .filter_map(|(tag, line)| {
+                let sign = match tag {
+                    ChangeTag::Delete => "-",
+                    ChangeTag::Insert => "+",
+                    ChangeTag::Equal => return None,
+                };
+
+                Some(format!("{}{}", sign, line.trim_end()))
+            })
+            .collect();
+
+        if changed_lines.is_empty() {
+            continue;
+        }
+
+        details.push_str(&format!("\ndiff for {}:\n", request.uri));
+
+        for line in changed_lines.iter().take(MAX_DIFF_LINES) {
+            details.push_str(line);
+            details.push('\n');
+        }
+
+        if changed_lines.len() > MAX_DIFF_LINES {
+            details.push_str(&format!(
+                "... {} more changed lines\n",
+                changed_lines.len() - MAX_DIFF_LINES
+            ));
+        }
+    }
+
+    details
+}
+
+fn get_response_status(request: &Request) -> String {
+    match &request.response {
+        Some(ResponseVariant::Success(response)) => {
+            response.status_code.to_string()
+        }
+        Some(ResponseVariant::Fail(error)) => format!("error ({})", error),
+        None => "no response".to_owned(),
+    }
+}
+
+fn is_job_completed(job: &JobDTO) -> bool {
+    matches!(job.status, JobStatus::Failed | JobStatus::Finished)
+}
+
+fn get_total_seconds<'a>(jobs: impl Iterator<Item = &'a JobDTO>) -> f64 {
+    jobs.filter_map(|job| job.job_duration).sum::<Duration>().as_secs_f64()
+}
+
+pub fn escape_xml(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+
+    for char in input.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // control characters are not allowed in xml 1.0
+            '\n' | '\r' | '\t' => escaped.push(char),
+            char if char.is_control() => {}
+            char => escaped.push(char),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,128 @@
+use std::{collections::HashMap, time::Duration};
+
+use similar::ChangeTag;
+use url::Url;
+
+use super::junit::map_jobs_to_junit_report;
+use crate::http_diff::{
+    job::JobDTO,
+    request::{Request, Response, ResponseVariant},
+    types::{HttpMethod, JobStatus},
+};
+
+fn create_request(uri: &str, status_code: u16, status: JobStatus) -> Request {
+    let mut request =
+        Request::new(&Url::parse(uri).unwrap(), &HttpMethod::GET, None, None);
+
+    request.status = status;
+    request.response = Some(ResponseVariant::Success(Response {
+        status_code,
+        content_length: None,
+        headers: HashMap::new(),
+        body: None,
+    }));
+
+    request
+}
+
+fn create_job(
+    job_name: &str,
+    status: JobStatus,
+    requests: Vec<Request>,
+) -> JobDTO {
+    JobDTO {
+        requests,
+        status,
+        job_duration: Some(Duration::from_millis(250)),
+        job_name: job_name.to_owned(),
+        endpoint: "/api/users/<id>".to_owned(),
+    }
+}
+
+#[test]
+pub fn test_maps_jobs_to_junit_report() {
+    let mut failed_request = create_request(
+        "http://domain-b.com/api/users/2",
+        500,
+        JobStatus::Failed,
+    );
+
+    failed_request.has_diffs = true;
+    failed_request.diffs = vec![
+        (ChangeTag::Equal, "{\n".to_owned()),
+        (ChangeTag::Delete, "  \"name\": \"<test>\"\n".to_owned()),
+        (ChangeTag::Insert, "  \"error\": true\n".to_owned()),
+    ];
+
+    let jobs = vec![
+        create_job(
+            "/api/users/1",
+            JobStatus::Finished,
+            vec![
+                create_request(
+                    "http://domain-a.com/api/users/1",
+                    200,
+                    JobStatus::Finished,
+                ),
+                create_request(
+                    "http://domain-b.com/api/users/1",
+                    200,
+                    JobStatus::Finished,
+                ),
+            ],
+        ),
+        create_job(
+            "/api/users/2",
+            JobStatus::Failed,
+            vec![
+                create_request(
+                    "http://domain-a.com/api/users/2",
+                    200,
+                    JobStatus::Finished,
+                ),
+                failed_request,
+            ],
+        ),
+    ];
+
+    let actual = map_jobs_to_junit_report(&jobs);
+
+    let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="http-diff" tests="2" failures="1" time="0.500">
+  <testsuite name="/api/users/&lt;id&gt;" tests="2" failures="1" skipped="0" time="0.500">
+    <testcase name="/api/users/1" classname="/api/users/&lt;id&gt;" time="0.250"/>
+    <testcase name="/api/users/2" classname="/api/users/&lt;id&gt;" time="0.250">
+      <failure message="http://domain-a.com 200, http://domain-b.com 500">status codes:
+  http://domain-a.com/api/users/2: 200
+  http://domain-b.com/api/users/2: 500
+
+diff for http://domain-b.com/api/users/2:
+-  &quot;name&quot;: &quot;&lt;test&gt;&quot;
++  &quot;error&quot;: true
+</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#;
+
+    assert_eq!(actual, expected)
+}
+
+#[test]
+pub fn test_marks_unfinished_jobs_as_skipped() {
+    let jobs = vec![create_job(
+        "/api/users/1",
+        JobStatus::Pending,
+        vec![Request::new(
+            &Url::parse("http://domain-a.com/api/users/1").unwrap(),
+            &HttpMethod::GET,
+            None,
+            None,
+        )],
+    )];
+
+    let actual = map_jobs_to_junit_report(&jobs);
+
+    assert!(actual.contains("skipped=\"1\""));
+    assert!(actual.contains("<skipped/>"));
+}
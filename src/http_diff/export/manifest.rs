@@ -0,0 +1,100 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+use tokio::{
+    fs::{create_dir_all, read_to_string, remove_file, File},
+    io::AsyncWriteExt,
+    sync::Mutex,
+};
+
+use super::super::config::APP_VERSION;
+
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Jobs are saved by concurrent tasks, manifest is read and written
+/// by one of them at a time so saved jobs are not lost
+static MANIFEST_LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct RunManifest {
+    pub version: String,
+    pub jobs: Vec<ManifestJob>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ManifestJob {
+    pub name: String,
+    pub endpoint: String,
+    pub status: String,
+    pub duration_ms: Option<u64>,
+    /// relative to the run directory
+    pub directory: String,
+    pub requests: Vec<ManifestRequest>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ManifestRequest {
+    pub uri: String,
+    pub status: String,
+    pub status_code: Option<u16>,
+    pub response_file: String,
+    pub request_file: String,
+    pub diff_file: Option<String>,
+}
+
+/// Adds saved jobs to `manifest.json` of the run directory,
+/// jobs saved earlier are replaced by name
+pub async fn save_manifest(
+    base_directory: &Path,
+    saved_jobs: Vec<ManifestJob>,
+) -> Result<PathBuf> {
+    create_dir_all(base_directory).await?;
+
+    let manifest_path = base_directory.join(MANIFEST_FILE_NAME);
+
+    let _lock = MANIFEST_LOCK.lock().await;
+
+    let mut manifest = match load_manifest(&manifest_path).await {
+        Some(manifest) => manifest,
+        None => {
+            RunManifest { version: APP_VERSION.to_owned(), jobs: Vec::new() }
+        }
+    };
+
+    for saved_job in saved_jobs {
+        match manifest.jobs.iter_mut().find(|job| job.name == saved_job.name) {
+            Some(existing_job) => *existing_job = saved_job,
+            None => manifest.jobs.push(saved_job),
+        }
+    }
+
+    let mut file = File::create(&manifest_path).await?;
+
+    file.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())
+        .await?;
+
+    file.flush().await?;
+
+    Ok(manifest_path)
+}
+
+/// Removes manifest of the run directory when all jobs are started again,
+/// so jobs of previous runs are not listed
+pub async fn reset_manifest(base_directory: &Path) -> Result<()> {
+    let _lock = MANIFEST_LOCK.lock().await;
+
+    match remove_file(base_directory.join(MANIFEST_FILE_NAME)).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+pub async fn load_manifest(manifest_path: &Path) -> Option<RunManifest> {
+    let buffer = read_to_string(manifest_path).await.ok()?;
+
+    serde_json::from_str(&buffer).ok()
+}
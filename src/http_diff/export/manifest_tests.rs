@@ -0,0 +1,71 @@
+use std::env;
+
+use tokio::fs::remove_dir_all;
+use uuid::Uuid;
+
+use super::manifest::{
+    load_manifest, reset_manifest, save_manifest, ManifestJob,
+};
+
+fn create_manifest_job(index: usize) -> ManifestJob {
+    ManifestJob {
+        name: format!("/api/users/{}", index),
+        endpoint: "/api/users/<id>".to_owned(),
+        status: "failed".to_owned(),
+        duration_ms: None,
+        directory: format!("api_users_{}", index),
+        requests: Vec::new(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+pub async fn test_keeps_jobs_saved_concurrently() {
+    let directory =
+        env::temp_dir().join(format!("http-diff-{}", Uuid::new_v4()));
+
+    let tasks: Vec<_> = (0..20)
+        .map(|index| {
+            let directory = directory.clone();
+
+            tokio::spawn(async move {
+                save_manifest(&directory, vec![create_manifest_job(index)])
+                    .await
+                    .unwrap()
+            })
+        })
+        .collect();
+
+    let mut manifest_path = None;
+
+    for task in tasks {
+        manifest_path = Some(task.await.unwrap());
+    }
+
+    let manifest = load_manifest(&manifest_path.unwrap()).await.unwrap();
+
+    assert_eq!(manifest.jobs.len(), 20);
+
+    remove_dir_all(directory).await.unwrap();
+}
+
+#[tokio::test]
+pub async fn test_reset_manifest_drops_jobs_of_previous_run() {
+    let directory =
+        env::temp_dir().join(format!("http-diff-{}", Uuid::new_v4()));
+
+    save_manifest(&directory, vec![create_manifest_job(1)]).await.unwrap();
+
+    reset_manifest(&directory).await.unwrap();
+
+    let manifest_path =
+        save_manifest(&directory, vec![create_manifest_job(2)]).await.unwrap();
+
+    let manifest = load_manifest(&manifest_path).await.unwrap();
+
+    assert_eq!(manifest.jobs, vec![create_manifest_job(2)]);
+
+    // nothing to reset in a new run directory
+    remove_dir_all(&directory).await.unwrap();
+
+    reset_manifest(&directory).await.unwrap();
+}
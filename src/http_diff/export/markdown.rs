@@ -0,0 +1,271 @@
+use anyhow::Result;
+use similar::ChangeTag;
+use std::{
+    cmp::Reverse,
+    fs::{create_dir_all, File},
+    io::Write,
+    path::Path,
+};
+
+use super::super::job::JobDTO;
+use super::super::request::{Request, ResponseVariant};
+use super::junit::escape_xml;
+
+const SLOWEST_JOBS_COUNT: usize = 5;
+const DIFF_CONTEXT_LINES: usize = 3;
+const MAX_DIFF_LINES: usize = 40;
+
+/// Builds markdown summary for pull request comments.
+/// Failed jobs that don't fit into `size_limit` bytes are only counted.
+pub fn map_jobs_to_markdown_report(
+    jobs: &[JobDTO],
+    size_limit: usize,
+) -> String {
+    let failed_jobs: Vec<&JobDTO> =
+        jobs.iter().filter(|job| job.is_failed()).collect();
+
+    let passed_jobs_count = jobs.len() - failed_jobs.len();
+
+    let failure_rate = match jobs.len() {
+        0 => 0.0,
+        total => failed_jobs.len() as f64 * 100.0 / total as f64,
+    };
+
+    let mut report = format!(
+        "## http-diff results\n\n\
+         | Total | Passed | Failed | Failure rate |\n\
+         | --- | --- | --- | --- |\n\
+         | {} | {} | {} | {:.1}% |\n",
+        jobs.len(),
+        passed_jobs_count,
+        failed_jobs.len(),
+        failure_rate
+    );
+
+    report.push_str(&map_slowest_jobs_to_markdown(jobs));
+
+    if failed_jobs.is_empty() {
+        return truncate_to_size_limit(report, size_limit);
+    }
+
+    report.push_str("\n### Failed jobs\n\n");
+
+    for (index, job) in failed_jobs.iter().enumerate() {
+        let section = map_failed_job_to_markdown(job);
+
+        let skipped_jobs_note = format!(
+            "_{} more failed jobs are not shown due to size limit_\n",
+            failed_jobs.len() - index
+        );
+
+        if report.len() + section.len() + skipped_jobs_note.len() > size_limit
+        {
+            report.push_str(&skipped_jobs_note);
+            break;
+        }
+
+        report.push_str(&section);
+    }
+
+    truncate_to_size_limit(report, size_limit)
+}
+
+pub fn save_markdown_report(
+    jobs: &[JobDTO],
+    file_path: &Path,
+    size_limit: usize,
+) -> Result<()> {
+    if let Some(directory) = file_path.parent() {
+        if !directory.as_os_str().is_empty() && !directory.exists() {
+            create_dir_all(directory)?;
+        }
+    }
+
+    let mut file = File::create(file_path)?;
+
+    file.write_all(map_jobs_to_markdown_report(jobs, size_limit).as_bytes())?;
+
+    Ok(())
+}
+
+fn map_slowest_jobs_to_markdown(jobs: &[JobDTO]) -> String {
+    let mut finished_jobs: Vec<&JobDTO> =
+        jobs.iter().filter(|job| job.job_duration.is_some()).collect();
+
+    if finished_jobs.is_empty() {
+        return String::new();
+    }
+
+    finished_jobs.sort_by_key(|job| Reverse(job.job_duration));
+
+    let mut markdown = String::from(
+        "\n### Slowest jobs\n\n| Job | Duration |\n| --- | --- |\n",
+    );
+
+    for job in finished_jobs.iter().take(SLOWEST_JOBS_COUNT) {
+        markdown.push_str(&format!(
+            "| `{}` | {:.2} s |\n",
+            job.job_name.replace('|', "\\|"),
+            job.job_duration.unwrap_or_default().as_secs_f64()
+        ));
+    }
+
+    markdown
+}
+
+fn map_failed_job_to_markdown(job: &JobDTO) -> String {
+    let statuses: Vec<String> = job
+        .requests
+        .iter()
+        .map(|request| {
+            format!(
+                "{} {}",
+                request.uri.origin().ascii_serialization(),
+                get_response_status(request)
+            )
+        })
+        .collect();
+
+    let mut markdown = format!(
+        "<details>\n<summary><code>{}</code> {}</summary>\n\n",
+        escape_xml(&job.job_name),
+        escape_xml(&statuses.join(", "))
+    );
+
+    for request in job.requests.iter() {
+        for violation in request.violations.iter() {
+            let fence = get_code_fence(&violation.message, 1);
+
+            markdown.push_str(&format!(
+                "- {} violation for {}: {} {} {}\n",
+                violation.kind.get_label(),
+                request.uri,
+                fence,
+                violation.message,
+                fence
+            ));
+        }
+    }
+
+    let baseline = job.requests.first().map(|request| request.uri.as_str());
+
+    for request in job.get_requests_with_diffs() {
+        if !request.has_diffs {
+            continue;
+        }
+
+        let diff = format!(
+            "--- {}\n+++ {}\n{}",
+            baseline.unwrap_or_default(),
+            request.uri,
+            map_diffs_to_unified_excerpt(&request.diffs)
+        );
+
+        let fence = get_code_fence(&diff, 3);
+
+        markdown.push_str(&format!("\n{}diff\n{}{}\n", fence, diff, fence));
+    }
+
+    markdown.push_str("\n</details>\n\n");
+
+    markdown
+}
+
+/// Keeps changed lines with a few lines of context around them,
+/// skipped parts are marked with `@@ ... @@`
+pub fn map_diffs_to_unified_excerpt(diffs: &[(ChangeTag, String)]) -> String {
+    let mut visible_lines = vec![false; diffs.len()];
+    let mut visible_end = 0;
+
+    // context of adjacent changes overlaps, every line is marked once
+    for (changed_index, _) in diffs
+        .iter()
+        .enumerate()
+        .filter(|(_, (tag, _))| tag != &ChangeTag::Equal)
+    {
+        let start =
+            changed_index.saturating_sub(DIFF_CONTEXT_LINES).max(visible_end);
+        let end = (changed_index + DIFF_CONTEXT_LINES + 1).min(diffs.len());
+
+        for is_visible in visible_lines.iter_mut().take(end).skip(start) {
+            *is_visible = true;
+        }
+
+        visible_end = visible_end.max(end);
+    }
+
+    let mut excerpt = String::new();
+    let mut lines_count = 0;
+    let mut is_previous_visible = true;
+
+    for (index, (tag, line)) in diffs.iter().enumerate() {
+        if !visible_lines[index] {
+            is_previous_visible = false;
+            continue;
+        }
+
+        if lines_count == MAX_DIFF_LINES {
+            excerpt.push_str("@@ diff is truncated @@\n");
+            break;
+        }
+
+        if !is_previous_visible {
+            excerpt.push_str("@@ ... @@\n");
+        }
+
+        let sign = match tag {
+            ChangeTag::Delete => "-",
+            ChangeTag::Insert => "+",
+            ChangeTag::Equal => " ",
+        };
+
+        excerpt.push_str(&format!(
+            "{}{}\n",
+            sign,
+            line.trim_end_matches('\n')
+        ));
+
+        lines_count += 1;
+        is_previous_visible = true;
+    }
+
+    excerpt
+}
+
+/// Backticks longer than any backtick run of `content`,
+/// so the content can't close the code block or span early
+fn get_code_fence(content: &str, min_length: usize) -> String {
+    let longest_run = content
+        .split(|character| character != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+
+    "`".repeat(min_length.max(longest_run + 1))
+}
+
+fn get_response_status(request: &Request) -> String {
+    match &request.response {
+        Some(ResponseVariant::Success(response)) => {
+            response.status_code.to_string()
+        }
+        Some(ResponseVariant::Fail(_)) => "error".to_owned(),
+        None => "no response".to_owned(),
+    }
+}
+
+fn truncate_to_size_limit(mut report: String, size_limit: usize) -> String {
+    if report.len() <= size_limit {
+        return report;
+    }
+
+    let mut index = size_limit;
+
+    while !report.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    report.truncate(index);
+
+    report
+}
@@ -0,0 +1,183 @@
+use std::{collections::HashMap, time::Duration};
+
+use similar::ChangeTag;
+use url::Url;
+
+use super::markdown::{
+    map_diffs_to_unified_excerpt, map_jobs_to_markdown_report,
+};
+use crate::http_diff::{
+    job::JobDTO,
+    request::{Request, Response, ResponseVariant, Violation, ViolationKind},
+    types::{HttpMethod, JobStatus},
+};
+
+fn create_request(uri: &str, status_code: u16) -> Request {
+    let mut request =
+        Request::new(&Url::parse(uri).unwrap(), &HttpMethod::GET, None, None);
+
+    request.response = Some(ResponseVariant::Success(Response {
+        status_code,
+        content_length: None,
+        headers: HashMap::new(),
+        body: None,
+    }));
+
+    request
+}
+
+fn create_job(job_name: &str, is_failed: bool, duration_ms: u64) -> JobDTO {
+    let mut failed_request =
+        create_request(&format!("http://domain-b.com{}", job_name), 500);
+
+    if is_failed {
+        failed_request.status = JobStatus::Failed;
+        failed_request.has_diffs = true;
+        failed_request.diffs = vec![
+            (ChangeTag::Delete, "\"status\": 200\n".to_owned()),
+            (ChangeTag::Insert, "\"status\": 500\n".to_owned()),
+        ];
+    }
+
+    JobDTO {
+        requests: vec![
+            create_request(&format!("http://domain-a.com{}", job_name), 200),
+            failed_request,
+        ],
+        status: if is_failed {
+            JobStatus::Failed
+        } else {
+            JobStatus::Finished
+        },
+        job_duration: Some(Duration::from_millis(duration_ms)),
+        job_name: job_name.to_owned(),
+        endpoint: job_name.to_owned(),
+    }
+}
+
+#[test]
+pub fn test_maps_jobs_to_markdown_report() {
+    let jobs = vec![
+        create_job("/health", false, 100),
+        create_job("/api/users", true, 1500),
+    ];
+
+    let actual = map_jobs_to_markdown_report(&jobs, 65000);
+
+    let expected = "## http-diff results
+
+| Total | Passed | Failed | Failure rate |
+| --- | --- | --- | --- |
+| 2 | 1 | 1 | 50.0% |
+
+### Slowest jobs
+
+| Job | Duration |
+| --- | --- |
+| `/api/users` | 1.50 s |
+| `/health` | 0.10 s |
+
+### Failed jobs
+
+<details>
+<summary><code>/api/users</code> http://domain-a.com 200, http://domain-b.com 500</summary>
+
+
+```diff
+--- http://domain-a.com/api/users
++++ http://domain-b.com/api/users
+-\"status\": 200
++\"status\": 500
+```
+
+</details>
+
+";
+
+    assert_eq!(actual, expected)
+}
+
+#[test]
+pub fn test_skips_failed_jobs_above_size_limit() {
+    let jobs: Vec<JobDTO> = (0..20)
+        .map(|index| create_job(&format!("/api/users/{}", index), true, 10))
+        .collect();
+
+    let actual = map_jobs_to_markdown_report(&jobs, 2000);
+
+    assert!(actual.len() <= 2000);
+    assert!(actual.contains("| 20 | 0 | 20 | 100.0% |"));
+    assert!(actual
+        .ends_with("more failed jobs are not shown due to size limit_\n"));
+}
+
+#[test]
+pub fn test_maps_diffs_to_unified_excerpt() {
+    let mut diffs: Vec<(ChangeTag, String)> = (0..10)
+        .map(|index| (ChangeTag::Equal, format!("line {}\n", index)))
+        .collect();
+
+    diffs[7] = (ChangeTag::Insert, "added\n".to_owned());
+
+    let actual = map_diffs_to_unified_excerpt(&diffs);
+
+    let expected = "@@ ... @@
+ line 4
+ line 5
+ line 6
++added
+ line 8
+ line 9
+";
+
+    assert_eq!(actual, expected)
+}
+
+#[test]
+pub fn test_merges_overlapping_context_of_changes() {
+    let mut diffs: Vec<(ChangeTag, String)> = (0..20)
+        .map(|index| (ChangeTag::Equal, format!("line {}\n", index)))
+        .collect();
+
+    diffs[3] = (ChangeTag::Delete, "removed\n".to_owned());
+    diffs[6] = (ChangeTag::Insert, "added\n".to_owned());
+
+    let actual = map_diffs_to_unified_excerpt(&diffs);
+
+    let expected = " line 0
+ line 1
+ line 2
+-removed
+ line 4
+ line 5
++added
+ line 7
+ line 8
+ line 9
+";
+
+    assert_eq!(actual, expected)
+}
+
+#[test]
+pub fn test_fences_are_longer_than_backticks_of_content() {
+    let mut job = create_job("/api/users", true, 10);
+
+    job.requests[1].diffs = vec![
+        (ChangeTag::Delete, "\"text\": \"```\"\n".to_owned()),
+        (ChangeTag::Insert, "\"text\": \"````\"\n".to_owned()),
+    ];
+
+    job.requests[1].violations = vec![Violation {
+        kind: ViolationKind::Schema,
+        message: "/text: \"`a`\" is not valid".to_owned(),
+    }];
+
+    let actual = map_jobs_to_markdown_report(&[job], 65000);
+
+    assert!(actual.contains(
+        "- schema violation for http://domain-b.com/api/users: `` /text: \"`a`\" is not valid ``\n"
+    ));
+    assert!(actual.contains("\n`````diff\n--- http://domain-a.com"));
+    assert!(actual.contains("+\"text\": \"````\"\n`````\n"));
+}
@@ -0,0 +1,30 @@
+pub mod dry_run;
+pub mod har;
+pub mod html;
+pub mod junit;
+pub mod manifest;
+pub mod markdown;
+pub mod records;
+
+#[cfg(test)]
+mod dry_run_tests;
+#[cfg(test)]
+mod har_tests;
+#[cfg(test)]
+mod html_tests;
+#[cfg(test)]
+mod junit_tests;
+#[cfg(test)]
+mod manifest_tests;
+#[cfg(test)]
+mod markdown_tests;
+#[cfg(test)]
+mod records_tests;
+
+pub use dry_run::*;
+pub use har::*;
+pub use html::*;
+pub use junit::*;
+pub use manifest::*;
+pub use markdown::*;
+pub use records::*;
@@ -0,0 +1,129 @@
+use chrono::Utc;
+use serde_json::{json, Value};
+use similar::ChangeTag;
+
+use super::super::job::JobDTO;
+use super::super::request::{Request, ResponseVariant};
+use super::super::types::JobStatus;
+
+/// Maps finished job to a flat record for scripts consuming
+/// `--output-format json|ndjson`
+pub fn map_job_to_record(job: &JobDTO) -> Value {
+    let requests: Vec<Value> =
+        job.requests.iter().map(map_request_to_record).collect();
+
+    let failure_reason = job.requests.iter().find_map(get_failure_reason);
+
+    json!({
+        "type": "job",
+        "name": job.job_name,
+        "endpoint": job.endpoint,
+        "status": get_status_label(&job.status),
+        "duration_ms": job.job_duration.map(|duration| duration.as_millis() as u64),
+        "failure_reason": failure_reason,
+        "requests": requests,
+    })
+}
+
+pub fn map_jobs_to_summary_record(jobs: &[JobDTO]) -> Value {
+    let count_by_status = |status: JobStatus| {
+        jobs.iter().filter(|job| job.status == status).count()
+    };
+
+    let started_at = jobs
+        .iter()
+        .flat_map(|job| job.requests.iter())
+        .filter_map(|request| request.started_at)
+        .min();
+
+    let duration_ms = started_at.map(|started_at| {
+        (Utc::now() - started_at).num_milliseconds().max(0) as u64
+    });
+
+    json!({
+        "type": "summary",
+        "total": jobs.len(),
+        "passed": count_by_status(JobStatus::Finished),
+        "failed": count_by_status(JobStatus::Failed),
+        "duration_ms": duration_ms,
+    })
+}
+
+/// Document printed by `--output-format json` once all jobs are finished
+pub fn map_jobs_to_run_record(jobs: &[JobDTO]) -> Value {
+    let job_records: Vec<Value> = jobs
+        .iter()
+        .filter(|job| {
+            matches!(job.status, JobStatus::Finished | JobStatus::Failed)
+        })
+        .map(map_job_to_record)
+        .collect();
+
+    json!({
+        "jobs": job_records,
+        "summary": map_jobs_to_summary_record(jobs),
+    })
+}
+
+fn map_request_to_record(request: &Request) -> Value {
+    let count_diffs = |change_tag: ChangeTag| {
+        request.diffs.iter().filter(|(tag, _)| tag == &change_tag).count()
+    };
+
+    let error = match &request.response {
+        Some(ResponseVariant::Fail(error)) => Some(error),
+        _ => None,
+    };
+
+    json!({
+        "domain": request.uri.origin().ascii_serialization(),
+        "uri": request.uri.as_str(),
+        "status": get_status_label(&request.status),
+        "status_code": request.get_status_code(),
+        "duration_ms": request.job_duration.map(|duration| duration.as_millis() as u64),
+        "diff": {
+            "added": count_diffs(ChangeTag::Insert),
+            "removed": count_diffs(ChangeTag::Delete),
+        },
+        "error": error,
+        "failure_reason": get_failure_reason(request),
+    })
+}
+
+fn get_failure_reason(request: &Request) -> Option<String> {
+    if request.status != JobStatus::Failed {
+        return None;
+    }
+
+    if let Some(ResponseVariant::Fail(error)) = &request.response {
+        return Some(format!("request failed: {}", error));
+    }
+
+    if request.has_diffs {
+        return Some(format!("response of {} differs", request.uri));
+    }
+
+    if let Some(violation) = request.violations.first() {
+        return Some(format!(
+            "{} violation: {}",
+            violation.kind.get_label(),
+            violation.message
+        ));
+    }
+
+    match request.get_status_code() {
+        Some(status_code) => {
+            Some(format!("unexpected status code {}", status_code))
+        }
+        None => Some("request failed".to_owned()),
+    }
+}
+
+pub fn get_status_label(status: &JobStatus) -> &'static str {
+    match status {
+        JobStatus::Pending => "pending",
+        JobStatus::Running => "running",
+        JobStatus::Failed => "failed",
+        JobStatus::Finished => "passed",
+    }
+}
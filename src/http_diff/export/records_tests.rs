@@ -0,0 +1,180 @@
+use std::{collections::HashMap, time::Duration};
+
+use serde_json::{json, Value};
+use similar::ChangeTag;
+use url::Url;
+
+use super::records::{
+    map_job_to_record, map_jobs_to_run_record, map_jobs_to_summary_record,
+};
+use crate::http_diff::{
+    job::JobDTO,
+    request::{Request, Response, ResponseVariant},
+    types::{HttpMethod, JobStatus},
+};
+
+fn create_request(
+    uri: &str,
+    status: JobStatus,
+    response: ResponseVariant,
+) -> Request {
+    let mut request =
+        Request::new(&Url::parse(uri).unwrap(), &HttpMethod::GET, None, None);
+
+    request.status = status;
+    request.job_duration = Some(Duration::from_millis(40));
+    request.response = Some(response);
+
+    request
+}
+
+fn create_response(status_code: u16) -> ResponseVariant {
+    ResponseVariant::Success(Response {
+        status_code,
+        content_length: None,
+        headers: HashMap::new(),
+        body: None,
+    })
+}
+
+#[test]
+pub fn test_maps_job_to_record() {
+    let mut failed_request = create_request(
+        "http://domain-b.com/api/users/1",
+        JobStatus::Failed,
+        create_response(500),
+    );
+
+    failed_request.has_diffs = true;
+    failed_request.diffs = vec![
+        (ChangeTag::Equal, "{".to_owned()),
+        (ChangeTag::Delete, "\"id\": 1".to_owned()),
+        (ChangeTag::Insert, "\"error\": true".to_owned()),
+        (ChangeTag::Insert, "\"code\": 500".to_owned()),
+    ];
+
+    let job = JobDTO {
+        requests: vec![
+            create_request(
+                "http://domain-a.com/api/users/1",
+                JobStatus::Finished,
+                create_response(200),
+            ),
+            failed_request,
+        ],
+        status: JobStatus::Failed,
+        job_duration: Some(Duration::from_millis(90)),
+        job_name: "/api/users/1".to_owned(),
+        endpoint: "/api/users/<id>".to_owned(),
+    };
+
+    let actual = map_job_to_record(&job);
+
+    let expected = json!({
+        "type": "job",
+        "name": "/api/users/1",
+        "endpoint": "/api/users/<id>",
+        "status": "failed",
+        "duration_ms": 90,
+        "failure_reason": "response of http://domain-b.com/api/users/1 differs",
+        "requests": [
+            {
+                "domain": "http://domain-a.com",
+                "uri": "http://domain-a.com/api/users/1",
+                "status": "passed",
+                "status_code": 200,
+                "duration_ms": 40,
+                "diff": { "added": 0, "removed": 0 },
+                "error": null,
+                "failure_reason": null
+            },
+            {
+                "domain": "http://domain-b.com",
+                "uri": "http://domain-b.com/api/users/1",
+                "status": "failed",
+                "status_code": 500,
+                "duration_ms": 40,
+                "diff": { "added": 2, "removed": 1 },
+                "error": null,
+                "failure_reason": "response of http://domain-b.com/api/users/1 differs"
+            }
+        ]
+    });
+
+    assert_eq!(actual, expected)
+}
+
+#[test]
+pub fn test_maps_jobs_to_summary_record() {
+    let create_job = |job_name: &str, status: JobStatus| JobDTO {
+        requests: vec![create_request(
+            "http://domain-a.com/",
+            status.clone(),
+            ResponseVariant::Fail("connection refused".to_owned()),
+        )],
+        status,
+        job_duration: None,
+        job_name: job_name.to_owned(),
+        endpoint: job_name.to_owned(),
+    };
+
+    let jobs = vec![
+        create_job("/a", JobStatus::Finished),
+        create_job("/b", JobStatus::Failed),
+        create_job("/c", JobStatus::Failed),
+    ];
+
+    let actual = map_jobs_to_summary_record(&jobs);
+
+    assert_eq!(
+        actual,
+        json!({
+            "type": "summary",
+            "total": 3,
+            "passed": 1,
+            "failed": 2,
+            "duration_ms": null
+        })
+    );
+
+    assert_eq!(
+        map_job_to_record(&jobs[1])["failure_reason"],
+        json!("request failed: connection refused")
+    );
+}
+
+#[test]
+pub fn test_json_output_is_a_single_document() {
+    let create_job = |job_name: &str, status: JobStatus| JobDTO {
+        requests: vec![create_request(
+            "http://domain-a.com/",
+            status.clone(),
+            create_response(200),
+        )],
+        status,
+        job_duration: None,
+        job_name: job_name.to_owned(),
+        endpoint: job_name.to_owned(),
+    };
+
+    let jobs = vec![
+        create_job("/a", JobStatus::Finished),
+        create_job("/b", JobStatus::Failed),
+        create_job("/c", JobStatus::Pending),
+    ];
+
+    let output =
+        serde_json::to_string_pretty(&map_jobs_to_run_record(&jobs)).unwrap();
+
+    let actual: Value = serde_json::from_str(&output).unwrap();
+
+    let job_names: Vec<&Value> = actual["jobs"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|job| &job["name"])
+        .collect();
+
+    assert_eq!(job_names, vec![&json!("/a"), &json!("/b")]);
+    assert_eq!(actual["summary"], map_jobs_to_summary_record(&jobs));
+}
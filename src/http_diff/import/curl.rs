@@ -0,0 +1,366 @@
+use serde_json::Value;
+use std::{collections::HashMap, fs::File, io::Read};
+use url::Url;
+
+use super::super::config::{
+    default_concurrent_jobs, Configuration, DomainVariant,
+    EndpointConfiguration,
+};
+use super::super::types::{AppError, HeaderValue, HeadersMap, HttpMethod};
+use super::super::utils::get_endpoint_from_url;
+
+const DATA_FLAGS: [&str; 6] = [
+    "-d",
+    "--data",
+    "--data-raw",
+    "--data-binary",
+    "--data-ascii",
+    "--data-urlencode",
+];
+
+// flags that take a value which is irrelevant for the comparison
+const SKIPPED_FLAGS_WITH_VALUE: [&str; 20] = [
+    "-o",
+    "--output",
+    "-m",
+    "--max-time",
+    "--connect-timeout",
+    "-x",
+    "--proxy",
+    "--retry",
+    "-w",
+    "--write-out",
+    "-F",
+    "--form",
+    "-u",
+    "--user",
+    "-e",
+    "--referer",
+    "--cacert",
+    "--cert",
+    "--key",
+    "--resolve",
+];
+
+const SKIPPED_HEADERS: [&str; 2] = ["host", "content-length"];
+
+pub fn load_curl_commands(
+    file_path: &str,
+) -> Result<Vec<Vec<String>>, AppError> {
+    let mut file = File::open(file_path)
+        .map_err(|_| AppError::FileNotFound(file_path.to_string()))?;
+
+    let mut buffer = String::new();
+
+    file.read_to_string(&mut buffer)
+        .map_err(|error| AppError::FailedToParseConfig(error.to_string()))?;
+
+    split_shell_commands(&buffer).map_err(|error| {
+        AppError::FailedToParseConfig(format!("{}: {}", file_path, error))
+    })
+}
+
+/// Commands that can't be imported are skipped,
+/// a warning is returned for each of them
+pub fn map_curl_commands_to_configuration(
+    commands: &[Vec<String>],
+    domains: Vec<DomainVariant>,
+) -> Result<(Configuration, Vec<String>), AppError> {
+    let mut endpoints: Vec<EndpointConfiguration> = Vec::new();
+    let mut warnings = Vec::new();
+
+    let curl_commands = commands.iter().filter(|arguments| {
+        arguments.first().map(String::as_str) == Some("curl")
+    });
+
+    for (index, arguments) in curl_commands.enumerate() {
+        let endpoint = match map_curl_arguments_to_endpoint(&arguments[1..]) {
+            Ok(endpoint) => endpoint,
+            Err(error) => {
+                warnings.push(format!(
+                    "Skipped curl command {}: {}",
+                    index + 1,
+                    error
+                ));
+                continue;
+            }
+        };
+
+        if !endpoints.contains(&endpoint) {
+            endpoints.push(endpoint);
+        }
+    }
+
+    if endpoints.is_empty() {
+        return Err(AppError::ValidationError(
+            "No curl commands were found".into(),
+        ));
+    }
+
+    let configuration = Configuration {
+        domains,
+        endpoints,
+        variables: None,
+        concurrent_jobs: default_concurrent_jobs(),
+        openapi: None,
+        replay: None,
+        save: None,
+        profiles: None,
+    };
+
+    Ok((configuration, warnings))
+}
+
+pub fn map_curl_arguments_to_endpoint(
+    arguments: &[String],
+) -> Result<EndpointConfiguration, AppError> {
+    let mut method: Option<String> = None;
+    let mut url: Option<String> = None;
+    let mut headers: HeadersMap = HashMap::new();
+    let mut data: Vec<String> = Vec::new();
+    let mut is_get_with_data = false;
+
+    let mut arguments = arguments.iter();
+
+    while let Some(argument) = arguments.next() {
+        let argument = argument.as_str();
+
+        match argument {
+            "-X" | "--request" => method = arguments.next().cloned(),
+            "-H" | "--header" => {
+                if let Some((name, value)) =
+                    arguments.next().and_then(|header| header.split_once(':'))
+                {
+                    headers.insert(
+                        name.trim().to_lowercase(),
+                        HeaderValue::String(value.trim().to_owned()),
+                    );
+                }
+            }
+            "--json" => {
+                data.extend(read_data_argument(argument, arguments.next())?);
+                headers.insert(
+                    "content-type".to_owned(),
+                    HeaderValue::String("application/json".to_owned()),
+                );
+            }
+            "-A" | "--user-agent" => {
+                if let Some(user_agent) = arguments.next() {
+                    headers.insert(
+                        "user-agent".to_owned(),
+                        HeaderValue::String(user_agent.clone()),
+                    );
+                }
+            }
+            "-b" | "--cookie" => {
+                if let Some(cookie) = arguments.next() {
+                    headers.insert(
+                        "cookie".to_owned(),
+                        HeaderValue::String(cookie.clone()),
+                    );
+                }
+            }
+            "--url" => url = arguments.next().cloned(),
+            "-G" | "--get" => is_get_with_data = true,
+            _ if DATA_FLAGS.contains(&argument) => {
+                data.extend(read_data_argument(argument, arguments.next())?)
+            }
+            _ if SKIPPED_FLAGS_WITH_VALUE.contains(&argument) => {
+                arguments.next();
+            }
+            _ if argument.starts_with("-X") => {
+                method = Some(argument[2..].to_owned())
+            }
+            _ if argument.starts_with('-') => {}
+            _ => {
+                if url.is_none() {
+                    url = Some(argument.to_owned())
+                }
+            }
+        }
+    }
+
+    let url = url.ok_or(AppError::ValidationError(
+        "curl command does not have a url".into(),
+    ))?;
+
+    let mut url = parse_curl_url(&url)?;
+
+    let data = data.join("&");
+
+    if is_get_with_data && !data.is_empty() {
+        let query = match url.query() {
+            Some(query) => format!("{}&{}", query, data),
+            None => data.clone(),
+        };
+
+        url.set_query(Some(&query));
+    }
+
+    let http_method = match method {
+        Some(method) => {
+            HttpMethod::from_name(&method).ok_or(AppError::ValidationError(
+                format!("Unsupported http method {}", method),
+            ))?
+        }
+        None if !data.is_empty() && !is_get_with_data => HttpMethod::POST,
+        None => HttpMethod::GET,
+    };
+
+    // only json payloads can be sent by http-diff
+    let body = match (&http_method, is_get_with_data) {
+        (HttpMethod::GET, _) | (_, true) => None,
+        _ => serde_json::from_str::<Value>(&data).ok(),
+    };
+
+    headers.retain(|name, _| !SKIPPED_HEADERS.contains(&name.as_str()));
+
+    Ok(EndpointConfiguration {
+        endpoint: get_endpoint_from_url(&url),
+        variables: None,
+        http_method: Some(http_method),
+        headers: if headers.is_empty() { None } else { Some(headers) },
+        body,
+        response_processor: None,
+        request_builder: None,
+        expect: None,
+        response_schema: None,
+        tags: None,
+    })
+}
+
+/// Data read by curl from a file, `@file` or `name@file`
+/// of `--data-urlencode`, is not available to the importer
+fn read_data_argument(
+    flag: &str,
+    data: Option<&String>,
+) -> Result<Option<String>, AppError> {
+    let data = match data {
+        Some(data) => data,
+        None => return Ok(None),
+    };
+
+    let is_read_from_file = match flag {
+        "--data-raw" => false,
+        "--data-urlencode" => data
+            .find(['=', '@'])
+            .is_some_and(|index| data[index..].starts_with('@')),
+        _ => data.starts_with('@'),
+    };
+
+    if is_read_from_file {
+        return Err(AppError::ValidationError(format!(
+            "{} {} reads data from a file, which is not supported",
+            flag, data
+        )));
+    }
+
+    Ok(Some(data.clone()))
+}
+
+fn parse_curl_url(url: &str) -> Result<Url, AppError> {
+    let url = match url.contains("://") {
+        true => url.to_owned(),
+        false => format!("http://{}", url),
+    };
+
+    Url::parse(&url).map_err(|error| {
+        AppError::ValidationError(format!("Invalid url {}: {}", url, error))
+    })
+}
+
+/// Splits shell script into commands and their arguments.
+/// Supports quotes, `$'...'` strings and line continuations,
+/// which covers "copy as cURL" output of browsers.
+pub fn split_shell_commands(script: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut commands: Vec<Vec<String>> = Vec::new();
+    let mut arguments: Vec<String> = Vec::new();
+    let mut current: Option<String> = None;
+
+    let mut chars = script.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next() {
+                Some('\n') | None => {}
+                Some('\r') => {
+                    chars.next_if_eq(&'\n');
+                }
+                Some(escaped) => current.get_or_insert_default().push(escaped),
+            },
+            '\'' => {
+                let value = current.get_or_insert_default();
+
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(char) => value.push(char),
+                        None => return Err("unterminated quote".into()),
+                    }
+                }
+            }
+            '$' if chars.peek() == Some(&'\'') => {
+                chars.next();
+
+                let value = current.get_or_insert_default();
+
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => value.push('\n'),
+                            Some('t') => value.push('\t'),
+                            Some('r') => value.push('\r'),
+                            Some(escaped) => value.push(escaped),
+                            None => return Err("unterminated quote".into()),
+                        },
+                        Some(char) => value.push(char),
+                        None => return Err("unterminated quote".into()),
+                    }
+                }
+            }
+            '"' => {
+                let value = current.get_or_insert_default();
+
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(escaped @ ('"' | '\\' | '$' | '`')) => {
+                                value.push(escaped)
+                            }
+                            Some('\n') => {}
+                            Some(char) => {
+                                value.push('\\');
+                                value.push(char);
+                            }
+                            None => return Err("unterminated quote".into()),
+                        },
+                        Some(char) => value.push(char),
+                        None => return Err("unterminated quote".into()),
+                    }
+                }
+            }
+            '#' if current.is_none() => {
+                while chars.next_if(|char| *char != '\n').is_some() {}
+            }
+            '\n' | ';' => {
+                arguments.extend(current.take());
+
+                if !arguments.is_empty() {
+                    commands.push(std::mem::take(&mut arguments));
+                }
+            }
+            char if char.is_whitespace() => arguments.extend(current.take()),
+            char => current.get_or_insert_default().push(char),
+        }
+    }
+
+    arguments.extend(current.take());
+
+    if !arguments.is_empty() {
+        commands.push(arguments);
+    }
+
+    Ok(commands)
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use super::curl::{
+    map_curl_arguments_to_endpoint, map_curl_commands_to_configuration,
+    split_shell_commands,
+};
+use crate::http_diff::types::{HeaderValue, HttpMethod};
+
+fn to_arguments(arguments: &[&str]) -> Vec<String> {
+    arguments.iter().map(|argument| argument.to_string()).collect()
+}
+
+#[test]
+pub fn test_splits_shell_commands() {
+    let script = r#"# users
+curl 'http://domain.com/users?a=1' \
+  -H "accept: application/json" \
+  --data-raw $'{"name":"it\'s"}'
+
+curl http://domain.com/health"#;
+
+    let actual = split_shell_commands(script).unwrap();
+
+    let expected = vec![
+        to_arguments(&[
+            "curl",
+            "http://domain.com/users?a=1",
+            "-H",
+            "accept: application/json",
+            "--data-raw",
+            r#"{"name":"it's"}"#,
+        ]),
+        to_arguments(&["curl", "http://domain.com/health"]),
+    ];
+
+    assert_eq!(actual, expected);
+
+    assert!(split_shell_commands("curl 'unterminated").is_err());
+}
+
+#[test]
+pub fn test_maps_curl_arguments_to_endpoint() {
+    let arguments = to_arguments(&[
+        "-XPUT",
+        "https://domain.com/api/users/1",
+        "-H",
+        "Content-Type: application/json",
+        "-H",
+        "Host: domain.com",
+        "--compressed",
+        "-d",
+        r#"{"username":"test"}"#,
+    ]);
+
+    let actual = map_curl_arguments_to_endpoint(&arguments).unwrap();
+
+    assert_eq!(actual.endpoint, "/api/users/1");
+    assert_eq!(actual.http_method, Some(HttpMethod::PUT));
+    assert_eq!(actual.body, Some(json!({ "username": "test" })));
+    assert_eq!(
+        actual.headers,
+        Some(HashMap::from([(
+            "content-type".to_owned(),
+            HeaderValue::String("application/json".to_owned())
+        )]))
+    );
+}
+
+#[test]
+pub fn test_curl_method_defaults() {
+    let actual = map_curl_arguments_to_endpoint(&to_arguments(&[
+        "domain.com/api/users",
+        "--data",
+        r#"{"username":"test"}"#,
+    ]))
+    .unwrap();
+
+    assert_eq!(actual.http_method, Some(HttpMethod::POST));
+
+    let actual = map_curl_arguments_to_endpoint(&to_arguments(&[
+        "-G",
+        "http://domain.com/search?q=1",
+        "-d",
+        "page=2",
+    ]))
+    .unwrap();
+
+    assert_eq!(actual.http_method, Some(HttpMethod::GET));
+    assert_eq!(actual.endpoint, "/search?q=1&page=2");
+    assert_eq!(actual.body, None);
+}
+
+#[test]
+pub fn test_skips_non_curl_commands_and_duplicates() {
+    let commands = split_shell_commands(
+        "export TOKEN=1\ncurl http://a.com/health\ncurl http://b.com/health",
+    )
+    .unwrap();
+
+    let (actual, warnings) =
+        map_curl_commands_to_configuration(&commands, vec![]).unwrap();
+
+    assert_eq!(actual.endpoints.len(), 1);
+    assert_eq!(actual.endpoints[0].endpoint, "/health");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+pub fn test_skips_curl_commands_that_fail_to_parse() {
+    let commands = split_shell_commands(
+        "curl -X FETCH http://a.com/users\n\
+         curl http://a.com/users -d @payload.json\n\
+         curl http://a.com/users --data-urlencode name@name.txt\n\
+         curl http://a.com/users --data-raw @literal\n\
+         curl http://a.com/health",
+    )
+    .unwrap();
+
+    let (actual, warnings) =
+        map_curl_commands_to_configuration(&commands, vec![]).unwrap();
+
+    let endpoints: Vec<&str> = actual
+        .endpoints
+        .iter()
+        .map(|endpoint| endpoint.endpoint.as_str())
+        .collect();
+
+    assert_eq!(endpoints, vec!["/users", "/health"]);
+
+    assert_eq!(warnings.len(), 3);
+    assert!(warnings[0].starts_with("Skipped curl command 1: "));
+    assert!(warnings[1].contains("-d @payload.json reads data from a file"));
+    assert!(warnings[2].contains("--data-urlencode name@name.txt"));
+}
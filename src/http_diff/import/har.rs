@@ -0,0 +1,195 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashMap, fs::File, io::Read};
+use url::Url;
+
+use super::super::config::{
+    default_concurrent_jobs, Configuration, DomainVariant,
+    EndpointConfiguration,
+};
+use super::super::types::{AppError, HeaderValue, HeadersMap, HttpMethod};
+use super::super::utils::get_endpoint_from_url;
+
+const CREDENTIAL_HEADERS: [&str; 3] = ["cookie", "authorization", "x-api-key"];
+
+// recomputed by the http client for every request
+const SKIPPED_HEADERS: [&str; 5] =
+    ["host", "content-length", "connection", "accept-encoding", "referer"];
+
+#[derive(Debug, Deserialize)]
+pub struct HarNameValue {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPostData {
+    pub mime_type: Option<String>,
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<HarNameValue>,
+    pub post_data: Option<HarPostData>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HarEntry {
+    pub request: HarRequest,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HarLog {
+    pub entries: Vec<HarEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HarDocument {
+    pub log: HarLog,
+}
+
+pub struct HarImportOptions {
+    pub hosts: Vec<String>,
+    pub include_credentials: bool,
+}
+
+pub fn load_har_document(file_path: &str) -> Result<HarDocument, AppError> {
+    let mut file = File::open(file_path)
+        .map_err(|_| AppError::FileNotFound(file_path.to_string()))?;
+
+    let mut buffer = String::new();
+
+    file.read_to_string(&mut buffer)
+        .map_err(|error| AppError::FailedToParseConfig(error.to_string()))?;
+
+    serde_json::from_str(&buffer).map_err(|error| {
+        AppError::FailedToParseConfig(format!("{}: {}", file_path, error))
+    })
+}
+
+pub fn map_har_to_configuration(
+    document: &HarDocument,
+    domains: Vec<DomainVariant>,
+    options: &HarImportOptions,
+) -> Result<Configuration, AppError> {
+    let mut endpoints: Vec<EndpointConfiguration> = Vec::new();
+
+    for entry in document.log.entries.iter() {
+        let endpoint =
+            match map_har_request_to_endpoint(&entry.request, options) {
+                Some(endpoint) => endpoint,
+                None => continue,
+            };
+
+        if !endpoints.contains(&endpoint) {
+            endpoints.push(endpoint);
+        }
+    }
+
+    if endpoints.is_empty() {
+        return Err(AppError::ValidationError(
+            "HAR file does not contain any importable requests".into(),
+        ));
+    }
+
+    Ok(Configuration {
+        domains,
+        endpoints,
+        variables: None,
+        concurrent_jobs: default_concurrent_jobs(),
+        openapi: None,
+        replay: None,
+        save: None,
+        profiles: None,
+    })
+}
+
+fn map_har_request_to_endpoint(
+    request: &HarRequest,
+    options: &HarImportOptions,
+) -> Option<EndpointConfiguration> {
+    let url = Url::parse(&request.url).ok()?;
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+
+    if !options.hosts.is_empty()
+        && !options
+            .hosts
+            .iter()
+            .any(|host| Some(host.as_str()) == url.host_str())
+    {
+        return None;
+    }
+
+    let http_method = HttpMethod::from_name(&request.method)?;
+
+    let endpoint = get_endpoint_from_url(&url);
+
+    let headers =
+        map_har_headers(&request.headers, options.include_credentials);
+
+    let body = match http_method {
+        HttpMethod::GET => None,
+        _ => request.post_data.as_ref().and_then(parse_json_body),
+    };
+
+    Some(EndpointConfiguration {
+        endpoint,
+        variables: None,
+        http_method: Some(http_method),
+        headers: if headers.is_empty() { None } else { Some(headers) },
+        body,
+        response_processor: None,
+        request_builder: None,
+        expect: None,
+        response_schema: None,
+        tags: None,
+    })
+}
+
+fn map_har_headers(
+    headers: &[HarNameValue],
+    include_credentials: bool,
+) -> HeadersMap {
+    let mut result = HashMap::new();
+
+    for header in headers {
+        let name = header.name.to_lowercase();
+
+        // http/2 pseudo headers like `:authority`
+        if name.starts_with(':') || SKIPPED_HEADERS.contains(&name.as_str()) {
+            continue;
+        }
+
+        if !include_credentials && CREDENTIAL_HEADERS.contains(&name.as_str())
+        {
+            continue;
+        }
+
+        result.insert(name, HeaderValue::String(header.value.clone()));
+    }
+
+    result
+}
+
+fn parse_json_body(post_data: &HarPostData) -> Option<Value> {
+    let is_json = post_data
+        .mime_type
+        .as_ref()
+        .map(|mime_type| mime_type.contains("json"))
+        .unwrap_or(true);
+
+    if !is_json {
+        return None;
+    }
+
+    serde_json::from_str(post_data.text.as_ref()?).ok()
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use url::Url;
+
+use super::har::{map_har_to_configuration, HarDocument, HarImportOptions};
+use crate::http_diff::{
+    config::DomainVariant,
+    types::{HeaderValue, HttpMethod},
+};
+
+fn get_test_document() -> HarDocument {
+    serde_json::from_value(json!({
+        "log": {
+            "entries": [
+                {
+                    "request": {
+                        "method": "GET",
+                        "url": "https://shop.example.com/api/users/1?expand=true",
+                        "headers": [
+                            { "name": ":authority", "value": "shop.example.com" },
+                            { "name": "Accept", "value": "application/json" },
+                            { "name": "Cookie", "value": "session=secret" },
+                            { "name": "Authorization", "value": "Bearer secret" }
+                        ]
+                    }
+                },
+                {
+                    "request": {
+                        "method": "GET",
+                        "url": "https://shop.example.com/api/users/1?expand=true",
+                        "headers": [
+                            { "name": "Accept", "value": "application/json" }
+                        ]
+                    }
+                },
+                {
+                    "request": {
+                        "method": "POST",
+                        "url": "https://shop.example.com/api/users",
+                        "headers": [],
+                        "postData": {
+                            "mimeType": "application/json",
+                            "text": "{\"username\":\"test\"}"
+                        }
+                    }
+                },
+                {
+                    "request": {
+                        "method": "GET",
+                        "url": "https://analytics.example.com/collect",
+                        "headers": []
+                    }
+                },
+                {
+                    "request": {
+                        "method": "OPTIONS",
+                        "url": "https://shop.example.com/api/users",
+                        "headers": []
+                    }
+                }
+            ]
+        }
+    }))
+    .unwrap()
+}
+
+fn get_test_domains() -> Vec<DomainVariant> {
+    vec![
+        DomainVariant::Url(Url::parse("http://domain-a.com").unwrap()),
+        DomainVariant::Url(Url::parse("http://domain-b.com").unwrap()),
+    ]
+}
+
+#[test]
+pub fn test_maps_har_entries_to_endpoints() {
+    let options = HarImportOptions {
+        hosts: vec!["shop.example.com".to_owned()],
+        include_credentials: false,
+    };
+
+    let actual = map_har_to_configuration(
+        &get_test_document(),
+        get_test_domains(),
+        &options,
+    )
+    .unwrap();
+
+    assert_eq!(actual.domains, get_test_domains());
+    assert_eq!(actual.endpoints.len(), 2);
+
+    let get_endpoint = &actual.endpoints[0];
+
+    assert_eq!(get_endpoint.endpoint, "/api/users/1?expand=true");
+    assert_eq!(get_endpoint.http_method, Some(HttpMethod::GET));
+    assert_eq!(
+        get_endpoint.headers,
+        Some(HashMap::from([(
+            "accept".to_owned(),
+            HeaderValue::String("application/json".to_owned())
+        )]))
+    );
+
+    let post_endpoint = &actual.endpoints[1];
+
+    assert_eq!(post_endpoint.endpoint, "/api/users");
+    assert_eq!(post_endpoint.http_method, Some(HttpMethod::POST));
+    assert_eq!(post_endpoint.body, Some(json!({ "username": "test" })));
+}
+
+#[test]
+pub fn test_keeps_credentials_when_asked() {
+    let options =
+        HarImportOptions { hosts: vec![], include_credentials: true };
+
+    let actual = map_har_to_configuration(
+        &get_test_document(),
+        get_test_domains(),
+        &options,
+    )
+    .unwrap();
+
+    let headers = actual.endpoints[0].headers.clone().unwrap();
+
+    assert_eq!(actual.endpoints.len(), 4);
+    assert_eq!(
+        headers.get("cookie"),
+        Some(&HeaderValue::String("session=secret".to_owned()))
+    );
+    assert_eq!(
+        headers.get("authorization"),
+        Some(&HeaderValue::String("Bearer secret".to_owned()))
+    );
+}
@@ -0,0 +1,18 @@
+pub mod curl;
+pub mod har;
+pub mod openapi;
+pub mod postman;
+
+#[cfg(test)]
+mod curl_tests;
+#[cfg(test)]
+mod har_tests;
+#[cfg(test)]
+mod openapi_tests;
+#[cfg(test)]
+mod postman_tests;
+
+pub use curl::*;
+pub use har::*;
+pub use openapi::*;
+pub use postman::*;
@@ -0,0 +1,260 @@
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::super::config::{
+    default_concurrent_jobs, Configuration, DomainVariant,
+    EndpointConfiguration,
+};
+use super::super::types::{
+    AppError, HeaderValue, HeadersMap, HttpMethod, PathVariable,
+    PathVariableValue, VariableGenerator, VariablesMap,
+};
+use super::super::validation::resolve_reference;
+
+pub fn map_openapi_to_configuration(
+    document: &Value,
+    domains: Vec<DomainVariant>,
+    include_non_get_operations: bool,
+) -> Result<Configuration, AppError> {
+    let paths = document.get("paths").and_then(Value::as_object).ok_or(
+        AppError::ValidationError(
+            "OpenAPI document does not define any paths".into(),
+        ),
+    )?;
+
+    let mut endpoints = Vec::new();
+
+    for (path_template, path_item) in paths {
+        let path_item = resolve_reference(document, path_item);
+
+        for (method_name, http_method) in [
+            ("get", HttpMethod::GET),
+            ("post", HttpMethod::POST),
+            ("put", HttpMethod::PUT),
+            ("patch", HttpMethod::PATCH),
+            ("delete", HttpMethod::DELETE),
+        ] {
+            if http_method != HttpMethod::GET && !include_non_get_operations {
+                continue;
+            }
+
+            let operation = match path_item.get(method_name) {
+                Some(operation) => operation,
+                None => continue,
+            };
+
+            endpoints.push(map_operation_to_endpoint(
+                document,
+                path_template,
+                path_item,
+                operation,
+                http_method,
+            ));
+        }
+    }
+
+    Ok(Configuration {
+        domains,
+        endpoints,
+        variables: None,
+        concurrent_jobs: default_concurrent_jobs(),
+        openapi: None,
+        replay: None,
+        save: None,
+        profiles: None,
+    })
+}
+
+fn map_operation_to_endpoint(
+    document: &Value,
+    path_template: &str,
+    path_item: &Value,
+    operation: &Value,
+    http_method: HttpMethod,
+) -> EndpointConfiguration {
+    let parameters: Vec<&Value> = [path_item, operation]
+        .iter()
+        .filter_map(|item| item.get("parameters")?.as_array())
+        .flatten()
+        .map(|parameter| resolve_reference(document, parameter))
+        .collect();
+
+    let mut endpoint = path_template_to_endpoint(path_template);
+    let mut variables: VariablesMap = HashMap::new();
+    let mut headers: HeadersMap = HashMap::new();
+    let mut query = Vec::new();
+
+    for parameter in parameters {
+        let name = match parameter.get("name").and_then(Value::as_str) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        let is_required = parameter
+            .get("required")
+            .and_then(Value::as_bool)
+            .unwrap_or_default();
+
+        match parameter.get("in").and_then(Value::as_str) {
+            Some("path") => {}
+            Some("query") if is_required => {
+                query.push(format!("{}=<{}>", name, name));
+            }
+            Some("header") if is_required => {
+                if let Some(example) = get_parameter_examples(parameter)
+                    .first()
+                    .and_then(Value::as_str)
+                {
+                    headers.insert(
+                        name.to_owned(),
+                        HeaderValue::String(example.to_owned()),
+                    );
+                }
+                continue;
+            }
+            _ => continue,
+        }
+
+        if let Some(variable) = map_parameter_to_variable(parameter) {
+            variables.insert(name.to_owned(), variable);
+        }
+    }
+
+    if !query.is_empty() {
+        endpoint = format!("{}?{}", endpoint, query.join("&"));
+    }
+
+    let body = match http_method {
+        HttpMethod::GET => None,
+        _ => get_request_body_example(document, operation),
+    };
+
+    EndpointConfiguration {
+        endpoint,
+        variables: if variables.is_empty() { None } else { Some(variables) },
+        http_method: Some(http_method),
+        headers: if headers.is_empty() { None } else { Some(headers) },
+        body,
+        response_processor: None,
+        request_builder: None,
+        expect: None,
+        response_schema: None,
+        tags: None,
+    }
+}
+
+pub fn path_template_to_endpoint(path_template: &str) -> String {
+    let parameter_pattern = Regex::new(r"\{([^}]+)\}").unwrap();
+
+    parameter_pattern.replace_all(path_template, "<$1>").into_owned()
+}
+
+fn map_parameter_to_variable(parameter: &Value) -> Option<PathVariable> {
+    let schema = parameter.get("schema");
+
+    let is_uuid =
+        schema.and_then(|schema| schema.get("format")).and_then(Value::as_str)
+            == Some("uuid");
+
+    let mut values: Vec<PathVariableValue> = get_parameter_examples(parameter)
+        .iter()
+        .filter_map(map_value_to_variable_value)
+        .collect();
+
+    if values.is_empty() && is_uuid {
+        values.push(PathVariableValue::Generator(VariableGenerator::UUID));
+    }
+
+    match values.len() {
+        0 => None,
+        1 => values.pop().map(PathVariable::SingleValue),
+        _ => Some(PathVariable::MultipleValues(values)),
+    }
+}
+
+fn get_parameter_examples(parameter: &Value) -> Vec<Value> {
+    if let Some(example) = parameter.get("example") {
+        return vec![example.clone()];
+    }
+
+    if let Some(examples) =
+        parameter.get("examples").and_then(Value::as_object)
+    {
+        let values: Vec<Value> = examples
+            .values()
+            .filter_map(|example| example.get("value").cloned())
+            .collect();
+
+        if !values.is_empty() {
+            return values;
+        }
+    }
+
+    let schema = match parameter.get("schema") {
+        Some(schema) => schema,
+        None => return Vec::new(),
+    };
+
+    if let Some(example) = schema.get("example") {
+        return vec![example.clone()];
+    }
+
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        return values.clone();
+    }
+
+    schema.get("default").cloned().into_iter().collect()
+}
+
+pub fn map_value_to_variable_value(
+    value: &Value,
+) -> Option<PathVariableValue> {
+    match value {
+        Value::Number(number) => match number.as_u64() {
+            Some(int_value) => {
+                Some(PathVariableValue::Int(int_value as usize))
+            }
+            None => Some(PathVariableValue::String(number.to_string())),
+        },
+        Value::String(string_value) => {
+            Some(PathVariableValue::String(string_value.clone()))
+        }
+        Value::Bool(bool_value) => {
+            Some(PathVariableValue::String(bool_value.to_string()))
+        }
+        _ => None,
+    }
+}
+
+fn get_request_body_example(
+    document: &Value,
+    operation: &Value,
+) -> Option<Value> {
+    let request_body =
+        resolve_reference(document, operation.get("requestBody")?);
+
+    let media_type = request_body
+        .get("content")?
+        .as_object()?
+        .iter()
+        .find(|(media_type, _)| media_type.contains("json"))
+        .map(|(_, media_type)| media_type)?;
+
+    if let Some(example) = media_type.get("example") {
+        return Some(example.clone());
+    }
+
+    if let Some(example) = media_type
+        .get("examples")
+        .and_then(Value::as_object)
+        .and_then(|examples| examples.values().next())
+        .and_then(|example| resolve_reference(document, example).get("value"))
+    {
+        return Some(example.clone());
+    }
+
+    resolve_reference(document, media_type.get("schema")?)
+        .get("example")
+        .cloned()
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use url::Url;
+
+use super::openapi::{
+    map_openapi_to_configuration, path_template_to_endpoint,
+};
+use crate::http_diff::{
+    config::{DomainVariant, EndpointConfiguration},
+    types::{
+        HeaderValue, HttpMethod, PathVariable, PathVariableValue,
+        VariableGenerator,
+    },
+};
+
+fn get_test_document() -> serde_json::Value {
+    json!({
+        "openapi": "3.0.3",
+        "paths": {
+            "/users/{userId}": {
+                "parameters": [{ "$ref": "#/components/parameters/UserId" }],
+                "get": {
+                    "parameters": [
+                        {
+                            "name": "status",
+                            "in": "query",
+                            "required": true,
+                            "schema": {
+                                "type": "string",
+                                "enum": ["active", "deleted"]
+                            }
+                        },
+                        {
+                            "name": "limit",
+                            "in": "query",
+                            "schema": { "type": "integer" }
+                        },
+                        {
+                            "name": "x-tenant",
+                            "in": "header",
+                            "required": true,
+                            "example": "main"
+                        }
+                    ]
+                },
+                "put": {
+                    "requestBody": {
+                        "content": {
+                            "application/json": {
+                                "example": { "username": "test" }
+                            }
+                        }
+                    }
+                }
+            },
+            "/orders/{orderId}": {
+                "get": {
+                    "parameters": [{
+                        "name": "orderId",
+                        "in": "path",
+                        "required": true,
+                        "schema": { "type": "string", "format": "uuid" }
+                    }]
+                }
+            }
+        },
+        "components": {
+            "parameters": {
+                "UserId": {
+                    "name": "userId",
+                    "in": "path",
+                    "required": true,
+                    "example": 123,
+                    "schema": { "type": "integer" }
+                }
+            }
+        }
+    })
+}
+
+fn get_test_domains() -> Vec<DomainVariant> {
+    vec![
+        DomainVariant::Url(Url::parse("http://domain-a.com").unwrap()),
+        DomainVariant::Url(Url::parse("http://domain-b.com").unwrap()),
+    ]
+}
+
+#[test]
+pub fn test_path_template_to_endpoint() {
+    let actual = path_template_to_endpoint("/users/{userId}/orders/{id}");
+
+    assert_eq!(actual, "/users/<userId>/orders/<id>")
+}
+
+#[test]
+pub fn test_maps_get_operations_to_endpoints() {
+    let actual = map_openapi_to_configuration(
+        &get_test_document(),
+        get_test_domains(),
+        false,
+    )
+    .unwrap();
+
+    let expected = vec![
+        EndpointConfiguration {
+            endpoint: "/orders/<orderId>".to_owned(),
+            variables: Some(HashMap::from([(
+                "orderId".to_owned(),
+                PathVariable::SingleValue(PathVariableValue::Generator(
+                    VariableGenerator::UUID,
+                )),
+            )])),
+            http_method: Some(HttpMethod::GET),
+            headers: None,
+            body: None,
+            response_processor: None,
+            request_builder: None,
+            expect: None,
+            response_schema: None,
+            tags: None,
+        },
+        EndpointConfiguration {
+            endpoint: "/users/<userId>?status=<status>".to_owned(),
+            variables: Some(HashMap::from([
+                (
+                    "userId".to_owned(),
+                    PathVariable::SingleValue(PathVariableValue::Int(123)),
+                ),
+                (
+                    "status".to_owned(),
+                    PathVariable::MultipleValues(vec![
+                        PathVariableValue::String("active".to_owned()),
+                        PathVariableValue::String("deleted".to_owned()),
+                    ]),
+                ),
+            ])),
+            http_method: Some(HttpMethod::GET),
+            headers: Some(HashMap::from([(
+                "x-tenant".to_owned(),
+                HeaderValue::String("main".to_owned()),
+            )])),
+            body: None,
+            response_processor: None,
+            request_builder: None,
+            expect: None,
+            response_schema: None,
+            tags: None,
+        },
+    ];
+
+    assert_eq!(actual.domains, get_test_domains());
+    assert_eq!(actual.endpoints, expected)
+}
+
+#[test]
+pub fn test_maps_non_get_operations_with_example_body() {
+    let actual = map_openapi_to_configuration(
+        &get_test_document(),
+        get_test_domains(),
+        true,
+    )
+    .unwrap();
+
+    let put_endpoint = actual
+        .endpoints
+        .iter()
+        .find(|endpoint| endpoint.http_method == Some(HttpMethod::PUT))
+        .unwrap();
+
+    assert_eq!(actual.endpoints.len(), 3);
+    assert_eq!(put_endpoint.endpoint, "/users/<userId>");
+    assert_eq!(put_endpoint.body, Some(json!({ "username": "test" })));
+}
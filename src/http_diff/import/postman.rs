@@ -0,0 +1,333 @@
+use regex::Regex;
+use serde_json::Value;
+use std::{collections::HashMap, fs::File, io::Read};
+
+use super::super::config::{
+    default_concurrent_jobs, Configuration, DomainVariant,
+    EndpointConfiguration,
+};
+use super::super::types::{
+    AppError, HeaderValue, HeadersMap, HttpMethod, PathVariable, VariablesMap,
+};
+use super::super::utils::get_placeholders_from_string;
+use super::openapi::map_value_to_variable_value;
+
+const SKIPPED_HEADERS: [&str; 2] = ["host", "content-length"];
+
+pub fn load_postman_collection(file_path: &str) -> Result<Value, AppError> {
+    let mut file = File::open(file_path)
+        .map_err(|_| AppError::FileNotFound(file_path.to_string()))?;
+
+    let mut buffer = String::new();
+
+    file.read_to_string(&mut buffer)
+        .map_err(|error| AppError::FailedToParseConfig(error.to_string()))?;
+
+    serde_json::from_str(&buffer).map_err(|error| {
+        AppError::FailedToParseConfig(format!("{}: {}", file_path, error))
+    })
+}
+
+pub fn map_postman_to_configuration(
+    collection: &Value,
+    domains: Vec<DomainVariant>,
+) -> Result<Configuration, AppError> {
+    let items = collection.get("item").and_then(Value::as_array).ok_or(
+        AppError::ValidationError(
+            "Postman collection does not have any items".into(),
+        ),
+    )?;
+
+    let collection_variables = get_postman_variables(collection);
+
+    let mut endpoints = Vec::new();
+
+    map_postman_items(items, &[], &collection_variables, &mut endpoints);
+
+    if endpoints.is_empty() {
+        return Err(AppError::ValidationError(
+            "Postman collection does not have any importable requests".into(),
+        ));
+    }
+
+    let placeholders: Vec<String> = endpoints
+        .iter()
+        .flat_map(|endpoint: &EndpointConfiguration| {
+            get_placeholders_from_string(&endpoint.endpoint)
+        })
+        .collect();
+
+    let variables: VariablesMap = collection_variables
+        .iter()
+        .filter(|(name, _)| placeholders.contains(name))
+        .filter_map(|(name, value)| {
+            let value = map_value_to_variable_value(value)?;
+
+            Some((name.clone(), PathVariable::SingleValue(value)))
+        })
+        .collect();
+
+    Ok(Configuration {
+        domains,
+        endpoints,
+        variables: if variables.is_empty() { None } else { Some(variables) },
+        concurrent_jobs: default_concurrent_jobs(),
+        openapi: None,
+        replay: None,
+        save: None,
+        profiles: None,
+    })
+}
+
+fn map_postman_items(
+    items: &[Value],
+    folders: &[String],
+    collection_variables: &HashMap<String, Value>,
+    endpoints: &mut Vec<EndpointConfiguration>,
+) {
+    for item in items {
+        let name =
+            item.get("name").and_then(Value::as_str).unwrap_or_default();
+
+        if let Some(children) = item.get("item").and_then(Value::as_array) {
+            let mut folders = folders.to_vec();
+
+            folders.push(name.to_owned());
+
+            map_postman_items(
+                children,
+                &folders,
+                collection_variables,
+                endpoints,
+            );
+
+            continue;
+        }
+
+        let request = match item.get("request") {
+            Some(request) => request,
+            None => continue,
+        };
+
+        if let Some(mut endpoint) =
+            map_postman_request_to_endpoint(request, collection_variables)
+        {
+            if !folders.is_empty() {
+                endpoint.tags = Some(folders.to_vec());
+            }
+
+            endpoints.push(endpoint);
+        }
+    }
+}
+
+fn map_postman_request_to_endpoint(
+    request: &Value,
+    collection_variables: &HashMap<String, Value>,
+) -> Option<EndpointConfiguration> {
+    // request can be just a url string
+    let (method, url) = match request {
+        Value::String(_) => ("GET", request),
+        _ => (
+            request.get("method").and_then(Value::as_str).unwrap_or("GET"),
+            request.get("url")?,
+        ),
+    };
+
+    let http_method = HttpMethod::from_name(method)?;
+
+    let endpoint = get_postman_endpoint(url)?;
+
+    let path_variables: VariablesMap = get_postman_variables(url)
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = map_value_to_variable_value(value)?;
+
+            Some((name.clone(), PathVariable::SingleValue(value)))
+        })
+        .collect();
+
+    let headers = get_postman_headers(request, collection_variables);
+
+    let body = match http_method {
+        HttpMethod::GET => None,
+        _ => get_postman_body(request),
+    };
+
+    Some(EndpointConfiguration {
+        endpoint,
+        variables: if path_variables.is_empty() {
+            None
+        } else {
+            Some(path_variables)
+        },
+        http_method: Some(http_method),
+        headers: if headers.is_empty() { None } else { Some(headers) },
+        body,
+        response_processor: None,
+        request_builder: None,
+        expect: None,
+        response_schema: None,
+        tags: None,
+    })
+}
+
+fn get_postman_endpoint(url: &Value) -> Option<String> {
+    let path = match url.get("path") {
+        Some(Value::Array(segments)) => {
+            let segments: Vec<&str> = segments
+                .iter()
+                .filter_map(|segment| match segment {
+                    Value::String(segment) => Some(segment.as_str()),
+                    _ => segment.get("value")?.as_str(),
+                })
+                .collect();
+
+            let query: Vec<String> = url
+                .get("query")
+                .and_then(Value::as_array)
+                .map(|query| {
+                    query
+                        .iter()
+                        .filter(|parameter| !is_disabled(parameter))
+                        .filter_map(map_postman_query_parameter)
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            match query.is_empty() {
+                true => format!("/{}", segments.join("/")),
+                false => {
+                    format!("/{}?{}", segments.join("/"), query.join("&"))
+                }
+            }
+        }
+        _ => {
+            let raw = match url {
+                Value::String(raw) => raw.as_str(),
+                _ => url.get("raw")?.as_str()?,
+            };
+
+            strip_postman_host(raw)
+        }
+    };
+
+    Some(convert_postman_placeholders(&path))
+}
+
+fn map_postman_query_parameter(parameter: &Value) -> Option<String> {
+    let key = parameter.get("key")?.as_str()?;
+
+    match parameter.get("value").and_then(Value::as_str) {
+        Some(value) => Some(format!("{}={}", key, value)),
+        None => Some(key.to_owned()),
+    }
+}
+
+fn strip_postman_host(raw: &str) -> String {
+    let without_host = if raw.starts_with("{{") {
+        raw.split_once("}}").map(|(_, rest)| rest).unwrap_or_default()
+    } else {
+        let without_scheme =
+            raw.split_once("://").map(|(_, rest)| rest).unwrap_or(raw);
+
+        match without_scheme.starts_with('/') {
+            true => without_scheme,
+            false => without_scheme
+                .find(['/', '?'])
+                .map(|index| &without_scheme[index..])
+                .unwrap_or_default(),
+        }
+    };
+
+    match without_host.starts_with('/') {
+        true => without_host.to_owned(),
+        false => format!("/{}", without_host),
+    }
+}
+
+/// Converts `{{var}}` and `:var` path segments into `<var>` placeholders
+pub fn convert_postman_placeholders(path: &str) -> String {
+    let variable_pattern = Regex::new(r"\{\{([^}]+)\}\}").unwrap();
+    let path_variable_pattern = Regex::new(r"/:([A-Za-z0-9_]+)").unwrap();
+
+    let path = variable_pattern.replace_all(path, "<$1>");
+
+    path_variable_pattern.replace_all(&path, "/<$1>").into_owned()
+}
+
+fn get_postman_variables(value: &Value) -> HashMap<String, Value> {
+    value
+        .get("variable")
+        .and_then(Value::as_array)
+        .map(|variables| {
+            variables
+                .iter()
+                .filter(|variable| !is_disabled(variable))
+                .filter_map(|variable| {
+                    let key = variable.get("key")?.as_str()?;
+                    let value = variable.get("value")?;
+
+                    match value {
+                        Value::String(value) if value.is_empty() => None,
+                        _ => Some((key.to_owned(), value.clone())),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn get_postman_headers(
+    request: &Value,
+    collection_variables: &HashMap<String, Value>,
+) -> HeadersMap {
+    let variable_pattern = Regex::new(r"\{\{([^}]+)\}\}").unwrap();
+
+    let headers = match request.get("header").and_then(Value::as_array) {
+        Some(headers) => headers,
+        None => return HashMap::new(),
+    };
+
+    headers
+        .iter()
+        .filter(|header| !is_disabled(header))
+        .filter_map(|header| {
+            let name = header.get("key")?.as_str()?.to_lowercase();
+            let value = header.get("value")?.as_str()?;
+
+            if SKIPPED_HEADERS.contains(&name.as_str()) {
+                return None;
+            }
+
+            // headers don't support placeholders,
+            // so collection variables are resolved right away
+            let value = variable_pattern.replace_all(
+                value,
+                |captures: &regex::Captures| match collection_variables
+                    .get(&captures[1])
+                {
+                    Some(Value::String(value)) => value.clone(),
+                    Some(value) => value.to_string(),
+                    None => captures[0].to_owned(),
+                },
+            );
+
+            Some((name, HeaderValue::String(value.into_owned())))
+        })
+        .collect()
+}
+
+fn get_postman_body(request: &Value) -> Option<Value> {
+    let body = request.get("body")?;
+
+    if body.get("mode").and_then(Value::as_str) != Some("raw") {
+        return None;
+    }
+
+    serde_json::from_str(body.get("raw")?.as_str()?).ok()
+}
+
+fn is_disabled(value: &Value) -> bool {
+    value.get("disabled").and_then(Value::as_bool).unwrap_or_default()
+}
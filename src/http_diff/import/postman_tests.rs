@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use super::postman::{
+    convert_postman_placeholders, map_postman_to_configuration,
+};
+use crate::http_diff::types::{
+    HeaderValue, HttpMethod, PathVariable, PathVariableValue,
+};
+
+fn get_test_collection() -> serde_json::Value {
+    json!({
+        "info": {
+            "name": "users",
+            "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+        },
+        "variable": [
+            { "key": "baseUrl", "value": "https://api.example.com" },
+            { "key": "token", "value": "secret" },
+            { "key": "status", "value": "active" }
+        ],
+        "item": [
+            {
+                "name": "users",
+                "item": [
+                    {
+                        "name": "admin",
+                        "item": [{
+                            "name": "get user",
+                            "request": {
+                                "method": "GET",
+                                "header": [
+                                    { "key": "Authorization", "value": "Bearer {{token}}" },
+                                    { "key": "x-debug", "value": "1", "disabled": true }
+                                ],
+                                "url": {
+                                    "raw": "{{baseUrl}}/users/:userId?status={{status}}",
+                                    "host": ["{{baseUrl}}"],
+                                    "path": ["users", ":userId"],
+                                    "query": [{ "key": "status", "value": "{{status}}" }],
+                                    "variable": [{ "key": "userId", "value": "123" }]
+                                }
+                            }
+                        }]
+                    }
+                ]
+            },
+            {
+                "name": "create user",
+                "request": {
+                    "method": "POST",
+                    "url": "https://api.example.com/users",
+                    "body": {
+                        "mode": "raw",
+                        "raw": "{\"username\": \"test\"}"
+                    }
+                }
+            }
+        ]
+    })
+}
+
+#[test]
+pub fn test_converts_postman_placeholders() {
+    let actual = convert_postman_placeholders("/users/:userId?page={{page}}");
+
+    assert_eq!(actual, "/users/<userId>?page=<page>")
+}
+
+#[test]
+pub fn test_maps_postman_collection_to_configuration() {
+    let actual =
+        map_postman_to_configuration(&get_test_collection(), vec![]).unwrap();
+
+    assert_eq!(actual.endpoints.len(), 2);
+
+    let get_endpoint = &actual.endpoints[0];
+
+    assert_eq!(get_endpoint.endpoint, "/users/<userId>?status=<status>");
+    assert_eq!(get_endpoint.http_method, Some(HttpMethod::GET));
+    assert_eq!(
+        get_endpoint.tags,
+        Some(vec!["users".to_owned(), "admin".to_owned()])
+    );
+    assert_eq!(
+        get_endpoint.variables,
+        Some(HashMap::from([(
+            "userId".to_owned(),
+            PathVariable::SingleValue(PathVariableValue::String(
+                "123".to_owned()
+            ))
+        )]))
+    );
+    assert_eq!(
+        get_endpoint.headers,
+        Some(HashMap::from([(
+            "authorization".to_owned(),
+            HeaderValue::String("Bearer secret".to_owned())
+        )]))
+    );
+
+    let post_endpoint = &actual.endpoints[1];
+
+    assert_eq!(post_endpoint.endpoint, "/users");
+    assert_eq!(post_endpoint.tags, None);
+    assert_eq!(post_endpoint.body, Some(json!({ "username": "test" })));
+
+    assert_eq!(
+        actual.variables,
+        Some(HashMap::from([(
+            "status".to_owned(),
+            PathVariable::SingleValue(PathVariableValue::String(
+                "active".to_owned()
+            ))
+        )]))
+    );
+}
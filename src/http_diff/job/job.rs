@@ -1,11 +1,18 @@
-use super::super::request::{Request, RequestBuilderDTO, ResponseVariant};
-use super::super::types::{AppError, JobStatus};
+use super::super::config::EndpointConfiguration;
+use super::super::export::{get_status_label, ManifestJob, ManifestRequest};
+use super::super::request::{
+    Request, RequestBuilderDTO, ResponseVariant, Violation, ViolationKind,
+};
+use super::super::types::{AppError, JobStatus, ResponseExpectation};
 use super::super::utils::clean_special_chars_for_filename;
+use super::super::validation::{get_schema_violations, OpenApiSpecification};
 use crate::actions::AppAction;
 use anyhow::{bail, Result};
 use futures::future::join_all;
+use jsonschema::Validator;
+use serde_json::json;
 use similar::{ChangeTag, TextDiff};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::Arc;
 use std::time::Duration;
@@ -24,6 +31,7 @@ pub struct JobDTO {
     pub status: JobStatus,
     pub job_duration: Option<Duration>,
     pub job_name: String,
+    pub endpoint: String,
 }
 
 impl JobDTO {
@@ -51,9 +59,13 @@ pub struct Job {
     pub status: JobStatus,
     pub job_duration: Option<Duration>,
     pub job_name: String,
+    pub endpoint: String,
     pub app_actions_sender: broadcast::Sender<AppAction>,
     pub response_processor: Option<Vec<String>>,
     pub request_builder: Option<Vec<String>>,
+    pub expect: Option<ResponseExpectation>,
+    pub response_schema: Option<Arc<Validator>>,
+    pub openapi: Option<Arc<OpenApiSpecification>>,
 }
 
 impl PartialEq for Job {
@@ -62,6 +74,7 @@ impl PartialEq for Job {
             && self.status == other.status
             && self.job_duration == other.job_duration
             && self.job_name == other.job_name
+            && self.endpoint == other.endpoint
     }
 }
 
@@ -72,19 +85,22 @@ impl Job {
         app_actions_sender: broadcast::Sender<AppAction>,
         requests_semaphore: Arc<Semaphore>,
         threads_semaphore: Arc<Semaphore>,
-        response_processor: &Option<Vec<String>>,
-        request_builder: &Option<Vec<String>>,
+        endpoint_config: &EndpointConfiguration,
     ) -> Self {
         Job {
             requests,
             status: JobStatus::Pending,
             job_duration: None,
             job_name: job_name.to_string(),
+            endpoint: endpoint_config.endpoint.clone(),
             app_actions_sender,
             requests_semaphore,
             threads_semaphore,
-            response_processor: response_processor.clone(),
-            request_builder: request_builder.clone(),
+            response_processor: endpoint_config.response_processor.clone(),
+            request_builder: endpoint_config.request_builder.clone(),
+            expect: endpoint_config.expect.clone(),
+            response_schema: None,
+            openapi: None,
         }
     }
 
@@ -166,6 +182,8 @@ impl Job {
 
         self.publish_self();
 
+        self.validate_responses();
+
         self.calculate_job_diffs().await?;
 
         let some_failed =
@@ -182,6 +200,71 @@ impl Job {
         Ok(())
     }
 
+    pub fn validate_responses(&mut self) {
+        for request in self.requests.iter_mut() {
+            let response = match &request.response {
+                Some(ResponseVariant::Success(response)) => response,
+                _ => continue,
+            };
+
+            let mut violations = Vec::new();
+
+            if let Some(validator) = &self.response_schema {
+                let schema_violations =
+                    get_schema_violations(validator, &response.body);
+
+                violations.extend(schema_violations.into_iter().map(
+                    |message| Violation {
+                        kind: ViolationKind::Schema,
+                        message,
+                    },
+                ));
+            }
+
+            if let Some(openapi) = &self.openapi {
+                let openapi_violations = openapi.get_violations(
+                    &request.http_method,
+                    &request.uri,
+                    response,
+                );
+
+                violations.extend(openapi_violations.into_iter().map(
+                    |message| Violation {
+                        kind: ViolationKind::OpenApi,
+                        message,
+                    },
+                ));
+            }
+
+            request.violations = violations;
+        }
+    }
+
+    /// Replaces request with the output of `request_builder` command
+    pub async fn build_request(
+        request_builder_command: &Vec<String>,
+        request: &mut Request,
+    ) -> Result<()> {
+        match Job::apply_request_builder_to_request(
+            request_builder_command,
+            request,
+        )
+        .await
+        {
+            Ok(Some(request_builder_dto)) => {
+                request.apply_request_builder_dto(request_builder_dto);
+
+                Ok(())
+            }
+            _ => Err(AppError::Exception(format!(
+                "Failed to apply request builder: '{}' to request: '{}'",
+                request_builder_command.join(" "),
+                request.uri
+            ))
+            .into()),
+        }
+    }
+
     pub async fn apply_request_builder_to_request(
         request_builder_command: &Vec<String>,
         request: &Request,
@@ -344,6 +427,8 @@ impl Job {
             }
         };
 
+        let baseline_status_code = first_request.get_status_code();
+
         let old = Job::apply_response_processor(
             &self.response_processor,
             &first_response,
@@ -361,12 +446,15 @@ impl Job {
             first_request_diffs.push((ChangeTag::Equal, line.to_string()));
         }
 
-        first_request.set_diffs_and_calculate_status(first_request_diffs);
+        first_request
+            .set_diffs_and_calculate_status(first_request_diffs, &self.expect);
 
         for request in self.requests.iter_mut().skip(1) {
             let old = old.clone();
 
-            let second_response = match &request.response {
+            let second_response = match request
+                .get_comparable_response(&self.expect, baseline_status_code)
+            {
                 Some(res) => res,
                 None => {
                     return Err(AppError::ValidationError(format!(
@@ -401,7 +489,7 @@ impl Job {
 
             drop(permit);
 
-            request.set_diffs_and_calculate_status(diffs);
+            request.set_diffs_and_calculate_status(diffs, &self.expect);
         }
 
         if self.requests.iter().any(|job| job.status == JobStatus::Failed) {
@@ -451,12 +539,16 @@ impl From<Job> for JobDTO {
             status: job.status,
             job_duration: job.job_duration,
             job_name: job.job_name,
+            endpoint: job.endpoint,
         }
     }
 }
 
 impl JobDTO {
-    pub async fn save(&self, base_directory: &PathBuf) -> Result<()> {
+    async fn create_output_directory(
+        &self,
+        base_directory: &Path,
+    ) -> Result<PathBuf> {
         let base_path = base_directory
             .join(clean_special_chars_for_filename(&self.job_name));
 
@@ -464,22 +556,125 @@ impl JobDTO {
             create_dir_all(&base_path).await?;
         }
 
-        for job in &self.requests {
-            let file_name = format!(
-                "{}.json",
-                clean_special_chars_for_filename(job.uri.as_str())
-            );
-            let job_file_path = base_path.join(file_name);
+        Ok(base_path)
+    }
+
+    pub fn to_curl_script(&self) -> String {
+        let mut script = String::from("#!/usr/bin/env sh\n");
 
-            let mut file = File::create(&job_file_path).await?;
+        for request in self.requests.iter() {
+            script.push_str(&format!(
+                "\n# {}\n{}\n",
+                self.job_name,
+                request.to_curl_command()
+            ));
+        }
+
+        script
+    }
+
+    pub async fn save_curl_script(
+        &self,
+        base_directory: &Path,
+    ) -> Result<PathBuf> {
+        let base_path = self.create_output_directory(base_directory).await?;
+
+        let script_path = base_path.join("requests.sh");
+
+        let mut file = File::create(&script_path).await?;
+
+        file.write_all(self.to_curl_script().as_bytes()).await?;
+
+        debug!("curl script saved to: {:?}", script_path.to_str());
+
+        Ok(script_path)
+    }
+
+    /// Saves responses, request metadata and unified diffs
+    /// against the first domain, returns manifest entry of the job
+    pub async fn save(&self, base_directory: &Path) -> Result<ManifestJob> {
+        let base_path = self.create_output_directory(base_directory).await?;
+
+        let baseline_uri = self.requests.first().map(|request| &request.uri);
+
+        let mut saved_requests = Vec::new();
+
+        for (index, job) in self.requests.iter().enumerate() {
+            let file_name = clean_special_chars_for_filename(job.uri.as_str());
+
+            let response_file = format!("{}.json", file_name);
 
             let content = serde_json::to_string_pretty(&job.response)?;
 
-            file.write_all(content.as_bytes()).await?;
+            write_file(&base_path.join(&response_file), &content).await?;
+
+            let request_file = format!("{}.request.json", file_name);
+
+            let metadata = json!({
+                "method": job.http_method,
+                "uri": job.uri.as_str(),
+                "headers": job.headers,
+                "body": job.body,
+                "started_at": job
+                    .started_at
+                    .map(|started_at| started_at.to_rfc3339()),
+                "duration_ms": job
+                    .job_duration
+                    .map(|duration| duration.as_millis() as u64),
+            });
+
+            write_file(
+                &base_path.join(&request_file),
+                &serde_json::to_string_pretty(&metadata)?,
+            )
+            .await?;
+
+            let diff_file = match baseline_uri {
+                Some(baseline_uri) if index > 0 => {
+                    let diff_file = format!("{}.diff", file_name);
+
+                    write_file(
+                        &base_path.join(&diff_file),
+                        &job.to_unified_diff(baseline_uri),
+                    )
+                    .await?;
+
+                    Some(diff_file)
+                }
+                _ => None,
+            };
 
-            debug!("response saved to: {:?}", job_file_path.to_str());
+            debug!("response saved to: {:?}", base_path.join(&response_file));
+
+            saved_requests.push(ManifestRequest {
+                uri: job.uri.to_string(),
+                status: get_status_label(&job.status).to_owned(),
+                status_code: job.get_status_code(),
+                response_file,
+                request_file,
+                diff_file,
+            });
         }
 
-        Ok(())
+        self.save_curl_script(base_directory).await?;
+
+        Ok(ManifestJob {
+            name: self.job_name.clone(),
+            endpoint: self.endpoint.clone(),
+            status: get_status_label(&self.status).to_owned(),
+            duration_ms: self
+                .job_duration
+                .map(|duration| duration.as_millis() as u64),
+            directory: clean_special_chars_for_filename(&self.job_name),
+            requests: saved_requests,
+        })
     }
 }
+
+async fn write_file(file_path: &Path, content: &str) -> Result<()> {
+    let mut file = File::create(file_path).await?;
+
+    file.write_all(content.as_bytes()).await?;
+
+    Ok(())
+}
@@ -1,45 +1,63 @@
 use super::super::utils::flatten_variables_map;
 use crate::actions::AppAction;
 use anyhow::Result;
-use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{broadcast, Semaphore};
-use uuid::Uuid;
 
 use super::super::config::Configuration;
-use super::super::config::{DomainVariant, EndpointConfiguration};
+use super::super::config::{
+    get_endpoint_variables, DomainVariant, EndpointConfiguration,
+    EndpointFilter, ReplayConfiguration,
+};
+use super::super::replay::{
+    load_replay_entries, map_replay_entry_to_endpoint,
+};
 use super::super::request::Request;
 use super::super::types::{
-    AppError, HeadersMap, HttpMethod, PathVariableValue,
-    PlaceholderToValueMap, VariableGenerator, VariablesMap,
+    AppError, HeadersMap, HttpMethod, PlaceholderToValueMap,
 };
 use super::super::utils::{
-    get_placeholders_from_string, replace_placeholder_with_value,
+    format_endpoint_with_variables, get_placeholders_from_string,
+};
+use super::super::validation::{
+    compile_response_schema, OpenApiSpecification,
 };
 use super::job::Job;
 
 pub fn map_configuration_to_jobs(
     configuration: &Configuration,
+    endpoint_filter: &EndpointFilter,
     app_actions_sender: broadcast::Sender<AppAction>,
     requests_semaphore: Arc<Semaphore>,
     threads_semaphore: Arc<Semaphore>,
 ) -> Result<Vec<Job>, AppError> {
     let mut endpoints: Vec<Job> = Vec::new();
 
-    for endpoint_config in &configuration.endpoints {
-        let placeholders =
-            get_placeholders_from_string(&endpoint_config.endpoint);
+    let openapi = match &configuration.openapi {
+        Some(file_path) => {
+            Some(Arc::new(OpenApiSpecification::load(file_path)?))
+        }
+        None => None,
+    };
 
-        let mut endpoint_variable_lookup: VariablesMap = HashMap::new();
+    let endpoint_configs = configuration
+        .endpoints
+        .iter()
+        .filter(|endpoint_config| endpoint_filter.matches(endpoint_config));
 
-        if let Some(global_optional_variables) = &configuration.variables {
-            endpoint_variable_lookup.extend(global_optional_variables.clone());
-        }
+    for endpoint_config in endpoint_configs {
+        let response_schema = match &endpoint_config.response_schema {
+            Some(response_schema) => {
+                Some(Arc::new(compile_response_schema(response_schema)?))
+            }
+            None => None,
+        };
 
-        if let Some(endpoint_optional_variables) = &endpoint_config.variables {
-            endpoint_variable_lookup
-                .extend(endpoint_optional_variables.clone());
-        }
+        let placeholders =
+            get_placeholders_from_string(&endpoint_config.endpoint);
+
+        let endpoint_variable_lookup =
+            get_endpoint_variables(&configuration.variables, endpoint_config);
 
         let endpoint_placeholders_with_variables: Vec<&String> = placeholders
             .iter()
@@ -48,10 +66,9 @@ pub fn map_configuration_to_jobs(
             })
             .collect();
 
-        endpoint_variable_lookup.retain(|key, _| placeholders.contains(key));
-
         if endpoint_placeholders_with_variables.is_empty() {
-            let new_job = map_job_with_no_variables(
+            let mut new_job = map_job_with_no_variables(
+                &endpoint_config.endpoint,
                 &configuration.domains,
                 endpoint_config,
                 app_actions_sender.clone(),
@@ -59,13 +76,16 @@ pub fn map_configuration_to_jobs(
                 threads_semaphore.clone(),
             )?;
 
+            new_job.response_schema = response_schema.clone();
+            new_job.openapi = openapi.clone();
+
             endpoints.push(new_job);
         } else {
             let variable_map_combinations =
                 flatten_variables_map(endpoint_variable_lookup);
 
             for variables_combination in variable_map_combinations {
-                let new_job = map_job_with_variables(
+                let mut new_job = map_job_with_variables(
                     &variables_combination,
                     &endpoint_placeholders_with_variables,
                     &configuration.domains,
@@ -75,15 +95,63 @@ pub fn map_configuration_to_jobs(
                     threads_semaphore.clone(),
                 )?;
 
+                new_job.response_schema = response_schema.clone();
+                new_job.openapi = openapi.clone();
+
                 endpoints.push(new_job);
             }
         }
     }
 
+    if let Some(replay) = &configuration.replay {
+        for mut new_job in map_replay_to_jobs(
+            replay,
+            &configuration.domains,
+            endpoint_filter,
+            app_actions_sender.clone(),
+            requests_semaphore.clone(),
+            threads_semaphore.clone(),
+        )? {
+            new_job.openapi = openapi.clone();
+
+            endpoints.push(new_job);
+        }
+    }
+
     Ok(endpoints)
 }
 
+pub fn map_replay_to_jobs(
+    replay: &ReplayConfiguration,
+    domains: &Vec<DomainVariant>,
+    endpoint_filter: &EndpointFilter,
+    app_actions_sender: broadcast::Sender<AppAction>,
+    requests_semaphore: Arc<Semaphore>,
+    threads_semaphore: Arc<Semaphore>,
+) -> Result<Vec<Job>, AppError> {
+    load_replay_entries(replay)?
+        .iter()
+        .map(|entry| {
+            (entry.get_job_name(), map_replay_entry_to_endpoint(entry))
+        })
+        .filter(|(_, endpoint_config)| {
+            endpoint_filter.matches(endpoint_config)
+        })
+        .map(|(job_name, endpoint_config)| {
+            map_job_with_no_variables(
+                &job_name,
+                domains,
+                &endpoint_config,
+                app_actions_sender.clone(),
+                requests_semaphore.clone(),
+                threads_semaphore.clone(),
+            )
+        })
+        .collect()
+}
+
 fn map_job_with_no_variables(
+    job_name: &str,
     domains: &Vec<DomainVariant>,
     endpoint_config: &EndpointConfiguration,
     app_actions_sender: broadcast::Sender<AppAction>,
@@ -131,12 +199,11 @@ fn map_job_with_no_variables(
 
     Ok(Job::new(
         jobs,
-        &endpoint_config.endpoint,
+        job_name,
         app_actions_sender,
         requests_semaphore,
         threads_semaphore,
-        &endpoint_config.response_processor,
-        &endpoint_config.request_builder,
+        endpoint_config,
     ))
 }
 
@@ -151,30 +218,12 @@ fn map_job_with_variables(
 ) -> Result<Job, AppError> {
     let mut jobs: Vec<Request> = Vec::new();
 
-    let mut formatted_string = endpoint_config.endpoint.clone();
-
-    for placeholder in endpoint_placeholders_with_variables {
-        let value = variables_combination.get(*placeholder);
-        if value.is_none() {
-            continue;
-        }
-
-        let value_for_replacement = match value.unwrap() {
-            PathVariableValue::Generator(generator_type) => {
-                match generator_type {
-                    VariableGenerator::UUID => Uuid::new_v4().to_string(),
-                }
-            }
-            PathVariableValue::String(string_value) => string_value.clone(),
-            PathVariableValue::Int(int_value) => int_value.to_string(),
-        };
+    let formatted_string = format_endpoint_with_variables(
+        &endpoint_config.endpoint,
+        variables_combination,
+        endpoint_placeholders_with_variables,
+    );
 
-        formatted_string = replace_placeholder_with_value(
-            &formatted_string,
-            placeholder,
-            &value_for_replacement,
-        );
-    }
     for domain_variant in domains {
         let (domain, domain_headers) = match domain_variant {
             DomainVariant::Url(domain) => (domain.clone(), None),
@@ -214,8 +263,7 @@ fn map_job_with_variables(
         app_actions_sender.clone(),
         requests_semaphore.clone(),
         threads_semaphore.clone(),
-        &endpoint_config.response_processor,
-        &endpoint_config.request_builder,
+        endpoint_config,
     ))
 }
 
@@ -5,7 +5,7 @@ use tokio::sync::{broadcast, Semaphore};
 use url::Url;
 
 use super::super::config::{
-    Configuration, DomainVariant, EndpointConfiguration,
+    Configuration, DomainVariant, EndpointConfiguration, EndpointFilter,
     UrlWithOptionalHeaders,
 };
 use super::super::request::Request;
@@ -74,6 +74,9 @@ pub fn test_convert_config_jobs() {
                 body: None,
                 response_processor: None,
                 request_builder: None,
+                expect: None,
+                response_schema: None,
+                tags: None,
             },
             EndpointConfiguration {
                 endpoint: "/api/v1/users/<userId>?status=<status>".to_string(),
@@ -83,6 +86,9 @@ pub fn test_convert_config_jobs() {
                 body: None,
                 response_processor: None,
                 request_builder: None,
+                expect: None,
+                response_schema: None,
+                tags: None,
             },
             EndpointConfiguration {
                 endpoint: "/api/v1/accounts/<accountId>?admin=<admin_flag>"
@@ -105,14 +111,22 @@ pub fn test_convert_config_jobs() {
                 body: None,
                 response_processor: None,
                 request_builder: None,
+                expect: None,
+                response_schema: None,
+                tags: None,
             },
         ],
         variables: Some(expected_global_variables),
         concurrent_jobs: 20,
+        openapi: None,
+        replay: None,
+        save: None,
+        profiles: None,
     };
 
     let actual_jobs = map_configuration_to_jobs(
         &configuration,
+        &EndpointFilter::default(),
         app_actions_sender.clone(),
         jobs_semaphore.clone(),
         threads_semaphore.clone(),
@@ -132,6 +146,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: None,
                     body: None,
                 },
@@ -143,6 +159,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: None,
                     body: None,
                 },
@@ -154,6 +172,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([(
                         "cookie".to_owned(),
                         HeaderValue::String("auth=check".to_owned()),
@@ -164,9 +184,13 @@ pub fn test_convert_config_jobs() {
             status: JobStatus::Pending,
             job_duration: None,
             job_name: "/health".to_string(),
+            endpoint: "/health".to_string(),
             app_actions_sender: app_actions_sender.clone(),
             response_processor: None,
             request_builder: None,
+            expect: None,
+            response_schema: None,
+            openapi: None,
         },
         Job {
             requests_semaphore: jobs_semaphore.clone(),
@@ -180,6 +204,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: None,
                     body: None,
                 },
@@ -191,6 +217,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: None,
                     body: None,
                 },
@@ -205,6 +233,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([(
                         "cookie".to_owned(),
                         HeaderValue::String("auth=check".to_owned()),
@@ -215,9 +245,13 @@ pub fn test_convert_config_jobs() {
             status: JobStatus::Pending,
             job_duration: None,
             job_name: "/api/v1/users/123?status=deleted".to_string(),
+            endpoint: "/api/v1/users/<userId>?status=<status>".to_string(),
             app_actions_sender: app_actions_sender.clone(),
             response_processor: None,
             request_builder: None,
+            expect: None,
+            response_schema: None,
+            openapi: None,
         },
         Job {
             requests_semaphore: jobs_semaphore.clone(),
@@ -231,6 +265,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: None,
                     body: None,
                 },
@@ -242,6 +278,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: None,
                     body: None,
                 },
@@ -256,6 +294,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([(
                         "cookie".to_owned(),
                         HeaderValue::String("auth=check".to_owned()),
@@ -266,9 +306,13 @@ pub fn test_convert_config_jobs() {
             status: JobStatus::Pending,
             job_duration: None,
             job_name: "/api/v1/users/444?status=deleted".to_string(),
+            endpoint: "/api/v1/users/<userId>?status=<status>".to_string(),
             app_actions_sender: app_actions_sender.clone(),
             response_processor: None,
             request_builder: None,
+            expect: None,
+            response_schema: None,
+            openapi: None,
         },
         Job {
             requests_semaphore: jobs_semaphore.clone(),
@@ -282,6 +326,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([(
                         "X-test".to_owned(),
                         HeaderValue::String("test=true".to_owned()),
@@ -296,6 +342,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([(
                         "X-test".to_owned(),
                         HeaderValue::String("test=true".to_owned()),
@@ -313,6 +361,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([
                         (
                             "X-test".to_owned(),
@@ -329,9 +379,13 @@ pub fn test_convert_config_jobs() {
             status: JobStatus::Pending,
             job_duration: None,
             job_name: "/api/v1/accounts/123?admin=true".to_string(),
+            endpoint: "/api/v1/accounts/<accountId>?admin=<admin_flag>".to_string(),
             app_actions_sender: app_actions_sender.clone(),
             response_processor: None,
             request_builder: None,
+            expect: None,
+            response_schema: None,
+            openapi: None,
         },
         Job {
             requests_semaphore: jobs_semaphore.clone(),
@@ -345,6 +399,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([(
                         "X-test".to_owned(),
                         HeaderValue::String("test=true".to_owned()),
@@ -359,6 +415,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([(
                         "X-test".to_owned(),
                         HeaderValue::String("test=true".to_owned()),
@@ -376,6 +434,8 @@ pub fn test_convert_config_jobs() {
                     response: None,
                     diffs: Vec::new(),
                     has_diffs: false,
+                    violations: Vec::new(),
+                    started_at: None,
                     headers: Some(HashMap::from([
                         (
                             "X-test".to_owned(),
@@ -392,9 +452,13 @@ pub fn test_convert_config_jobs() {
             status: JobStatus::Pending,
             job_duration: None,
             job_name: "/api/v1/accounts/123?admin=false".to_string(),
+            endpoint: "/api/v1/accounts/<accountId>?admin=<admin_flag>".to_string(),
             app_actions_sender,
             response_processor: None,
             request_builder: None,
+            expect: None,
+            response_schema: None,
+            openapi: None,
         },
     ];
 
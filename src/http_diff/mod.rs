@@ -1,6 +1,10 @@
 pub mod app;
 pub mod config;
+pub mod export;
+pub mod import;
 pub mod job;
+pub mod replay;
 pub mod request;
 pub mod types;
 pub mod utils;
+pub mod validation;
@@ -0,0 +1,176 @@
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashSet, fs::File, io::Read, path::Path};
+
+use super::super::config::{
+    EndpointConfiguration, ReplayConfiguration, ReplayFormat,
+};
+use super::super::types::{AppError, HeadersMap, HttpMethod};
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ReplayEntry {
+    pub method: String,
+    pub path: String,
+    pub headers: Option<HeadersMap>,
+    pub body: Option<Value>,
+    /// line of the log file the entry is read from, starting from 1
+    #[serde(default)]
+    pub line: usize,
+}
+
+impl ReplayEntry {
+    /// The same path can be logged many times with different methods
+    /// and bodies, so jobs are named by the log line as well
+    pub fn get_job_name(&self) -> String {
+        format!("{} {} #{}", self.method.to_uppercase(), self.path, self.line)
+    }
+
+    pub fn get_http_method(&self) -> Option<HttpMethod> {
+        HttpMethod::from_name(&self.method)
+    }
+
+    pub fn get_normalized_path(&self) -> String {
+        let id_pattern =
+            Regex::new(r"^([0-9]+|[0-9a-fA-F-]{32,36}|[0-9a-fA-F]{24})$")
+                .unwrap();
+
+        let path = self.path.split('?').next().unwrap_or_default();
+
+        let segments: Vec<&str> = path
+            .split('/')
+            .map(|segment| match id_pattern.is_match(segment) {
+                true => "{id}",
+                false => segment,
+            })
+            .collect();
+
+        segments.join("/")
+    }
+}
+
+pub fn parse_combined_log_line(line: &str) -> Option<ReplayEntry> {
+    let line_pattern =
+        Regex::new(r#"^\S+ \S+ \S+ \[[^\]]+\] "(\S+) (\S+)[^"]*" \d{3} "#)
+            .unwrap();
+
+    let captures = line_pattern.captures(line)?;
+
+    Some(ReplayEntry {
+        method: captures[1].to_owned(),
+        path: captures[2].to_owned(),
+        headers: None,
+        body: None,
+        line: 0,
+    })
+}
+
+pub fn parse_jsonl_line(line: &str) -> Option<ReplayEntry> {
+    serde_json::from_str(line).ok()
+}
+
+fn get_replay_format(replay: &ReplayConfiguration) -> ReplayFormat {
+    if let Some(format) = &replay.format {
+        return format.clone();
+    }
+
+    let extension = Path::new(&replay.file)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+
+    match extension {
+        "jsonl" | "ndjson" => ReplayFormat::Jsonl,
+        _ => ReplayFormat::Combined,
+    }
+}
+
+pub fn load_replay_entries(
+    replay: &ReplayConfiguration,
+) -> Result<Vec<ReplayEntry>, AppError> {
+    let mut file = File::open(&replay.file)
+        .map_err(|_| AppError::FileNotFound(replay.file.clone()))?;
+
+    let mut buffer = String::new();
+
+    file.read_to_string(&mut buffer)
+        .map_err(|error| AppError::FailedToParseConfig(error.to_string()))?;
+
+    let parse_line = match get_replay_format(replay) {
+        ReplayFormat::Combined => parse_combined_log_line,
+        ReplayFormat::Jsonl => parse_jsonl_line,
+    };
+
+    // lines that can't be parsed or replayed are skipped,
+    // production logs tend to contain garbage
+    let entries: Vec<ReplayEntry> = buffer
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| {
+            parse_line(line)
+                .map(|entry| ReplayEntry { line: index + 1, ..entry })
+        })
+        .filter(|entry| entry.path.starts_with('/'))
+        .filter(|entry| entry.get_http_method().is_some())
+        .collect();
+
+    Ok(select_replay_entries(entries, replay))
+}
+
+pub fn select_replay_entries(
+    entries: Vec<ReplayEntry>,
+    replay: &ReplayConfiguration,
+) -> Vec<ReplayEntry> {
+    let mut seen_paths = HashSet::new();
+
+    let entries: Vec<ReplayEntry> = entries
+        .into_iter()
+        .filter(|entry| {
+            replay.include_mutating
+                || entry.get_http_method() == Some(HttpMethod::GET)
+        })
+        .filter(|entry| {
+            !replay.dedupe
+                || seen_paths.insert(format!(
+                    "{} {}",
+                    entry.method.to_uppercase(),
+                    entry.get_normalized_path()
+                ))
+        })
+        .collect();
+
+    let sample_size = match replay.sample {
+        Some(sample_size) if sample_size < entries.len() => sample_size,
+        _ => return entries,
+    };
+
+    let mut rng = match replay.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut indexes = sample(&mut rng, entries.len(), sample_size).into_vec();
+
+    indexes.sort_unstable();
+
+    indexes.into_iter().map(|index| entries[index].clone()).collect()
+}
+
+pub fn map_replay_entry_to_endpoint(
+    entry: &ReplayEntry,
+) -> EndpointConfiguration {
+    EndpointConfiguration {
+        endpoint: entry.path.clone(),
+        variables: None,
+        http_method: entry.get_http_method(),
+        headers: entry.headers.clone(),
+        body: entry.body.clone(),
+        response_processor: None,
+        request_builder: None,
+        expect: None,
+        response_schema: None,
+        tags: None,
+    }
+}
@@ -0,0 +1,200 @@
+use serde_json::json;
+use std::{env, fs, sync::Arc};
+use tokio::sync::{broadcast, Semaphore};
+use url::Url;
+use uuid::Uuid;
+
+use super::access_log::{
+    load_replay_entries, parse_combined_log_line, parse_jsonl_line,
+    select_replay_entries, ReplayEntry,
+};
+use crate::actions::AppAction;
+use crate::http_diff::config::{
+    load_config_from_file, ConfigurationOverrides, DomainVariant,
+    EndpointFilter, ReplayConfiguration,
+};
+use crate::http_diff::job::map_replay_to_jobs;
+
+fn create_entry(method: &str, path: &str) -> ReplayEntry {
+    ReplayEntry {
+        method: method.to_owned(),
+        path: path.to_owned(),
+        headers: None,
+        body: None,
+        line: 0,
+    }
+}
+
+fn create_replay_configuration() -> ReplayConfiguration {
+    ReplayConfiguration {
+        file: "access.log".to_owned(),
+        format: None,
+        sample: None,
+        seed: None,
+        dedupe: false,
+        include_mutating: false,
+    }
+}
+
+#[test]
+pub fn test_parses_combined_log_line() {
+    let line = r#"127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /api/users/1?expand=true HTTP/1.1" 200 2326 "http://example.com/start" "Mozilla/5.0""#;
+
+    let actual = parse_combined_log_line(line);
+
+    assert_eq!(actual, Some(create_entry("GET", "/api/users/1?expand=true")));
+
+    let actual = parse_combined_log_line("not an access log line");
+
+    assert_eq!(actual, None);
+}
+
+#[test]
+pub fn test_parses_jsonl_line() {
+    let line = r#"{"method": "POST", "path": "/api/users", "headers": {"x-test": "true"}, "body": {"username": "test"}}"#;
+
+    let actual = parse_jsonl_line(line).unwrap();
+
+    assert_eq!(actual.method, "POST");
+    assert_eq!(actual.path, "/api/users");
+    assert_eq!(actual.body, Some(json!({ "username": "test" })));
+    assert!(actual.headers.unwrap().contains_key("x-test"));
+}
+
+#[test]
+pub fn test_normalizes_ids_in_path() {
+    let entry = create_entry(
+        "GET",
+        "/api/users/123/orders/6f1c2a4e-9d0b-4c2e-8f3a-1b2c3d4e5f60?page=2",
+    );
+
+    assert_eq!(entry.get_normalized_path(), "/api/users/{id}/orders/{id}");
+}
+
+#[test]
+pub fn test_filters_mutating_methods_and_dedupes() {
+    let entries = vec![
+        create_entry("GET", "/api/users/1"),
+        create_entry("GET", "/api/users/2"),
+        create_entry("DELETE", "/api/users/1"),
+        create_entry("GET", "/health"),
+    ];
+
+    let actual =
+        select_replay_entries(entries.clone(), &create_replay_configuration());
+
+    assert_eq!(actual.len(), 3);
+
+    let replay = ReplayConfiguration {
+        dedupe: true,
+        include_mutating: true,
+        ..create_replay_configuration()
+    };
+
+    let actual = select_replay_entries(entries, &replay);
+
+    assert_eq!(
+        actual,
+        vec![
+            create_entry("GET", "/api/users/1"),
+            create_entry("DELETE", "/api/users/1"),
+            create_entry("GET", "/health"),
+        ]
+    );
+}
+
+#[test]
+pub fn test_sampling_with_seed_is_stable() {
+    let entries: Vec<ReplayEntry> = (0..100)
+        .map(|index| create_entry("GET", &format!("/api/users/{}", index)))
+        .collect();
+
+    let replay = ReplayConfiguration {
+        sample: Some(10),
+        seed: Some(42),
+        ..create_replay_configuration()
+    };
+
+    let first = select_replay_entries(entries.clone(), &replay);
+    let second = select_replay_entries(entries, &replay);
+
+    assert_eq!(first.len(), 10);
+    assert_eq!(first, second);
+}
+
+#[test]
+pub fn test_names_replayed_jobs_by_log_line() {
+    let file_path =
+        env::temp_dir().join(format!("http-diff-{}.log", Uuid::new_v4()));
+
+    let line = r#"127.0.0.1 - - [10/Oct/2000:13:55:36 -0700] "GET /api/users/1 HTTP/1.1" 200 2326 "-" "-""#;
+
+    fs::write(&file_path, format!("{}\n\n{}\n", line, line)).unwrap();
+
+    let replay = ReplayConfiguration {
+        file: file_path.to_string_lossy().to_string(),
+        ..create_replay_configuration()
+    };
+
+    let (app_actions_sender, _) = broadcast::channel::<AppAction>(1);
+
+    let jobs = map_replay_to_jobs(
+        &replay,
+        &vec![
+            DomainVariant::Url(Url::parse("http://domain-a.com").unwrap()),
+            DomainVariant::Url(Url::parse("http://domain-b.com").unwrap()),
+        ],
+        &EndpointFilter::default(),
+        app_actions_sender,
+        Arc::new(Semaphore::new(1)),
+        Arc::new(Semaphore::new(1)),
+    )
+    .unwrap();
+
+    fs::remove_file(file_path).unwrap();
+
+    let job_names: Vec<&str> =
+        jobs.iter().map(|job| job.job_name.as_str()).collect();
+
+    assert_eq!(job_names, vec!["GET /api/users/1 #1", "GET /api/users/1 #3"]);
+}
+
+#[test]
+pub fn test_loads_replay_file_relative_to_configuration() {
+    let directory =
+        env::temp_dir().join(format!("http-diff-{}", Uuid::new_v4()));
+
+    fs::create_dir_all(directory.join("logs")).unwrap();
+
+    fs::write(
+        directory.join("logs/access.jsonl"),
+        "{\"method\": \"GET\", \"path\": \"/api/users/1\"}\n",
+    )
+    .unwrap();
+
+    let configuration_path = directory.join("configuration.json");
+
+    fs::write(
+        &configuration_path,
+        r#"{
+          "domains": ["http://domain-a.com", "http://domain-b.com"],
+          "replay": { "file": "./logs/access.jsonl" }
+        }"#,
+    )
+    .unwrap();
+
+    // tests run from the crate root, not from the configuration directory
+    let (configuration, _) = load_config_from_file(
+        configuration_path.to_str().unwrap(),
+        &ConfigurationOverrides::default(),
+    )
+    .unwrap();
+
+    let entries =
+        load_replay_entries(configuration.replay.as_ref().unwrap()).unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].path, "/api/users/1");
+
+    fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,6 @@
+pub mod access_log;
+
+#[cfg(test)]
+mod access_log_tests;
+
+pub use access_log::*;
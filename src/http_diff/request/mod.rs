@@ -1,3 +1,6 @@
 pub mod request;
 
+#[cfg(test)]
+mod request_tests;
+
 pub use request::*;
@@ -1,7 +1,8 @@
+use chrono::{DateTime, Utc};
 use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
-use similar::ChangeTag;
+use similar::{ChangeTag, TextDiff};
 use std::{
     collections::BTreeMap,
     time::{Duration, Instant},
@@ -9,7 +10,10 @@ use std::{
 use tracing::error;
 use url::Url;
 
-use super::super::types::{HeadersMap, HttpMethod, JobStatus};
+use super::super::types::{
+    HeaderValue, HeadersMap, HttpMethod, JobStatus, ResponseExpectation,
+};
+use super::super::utils::quote_shell_argument;
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Response {
@@ -27,6 +31,27 @@ pub enum ResponseVariant {
     Fail(String),
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ViolationKind {
+    Schema,
+    OpenApi,
+}
+
+impl ViolationKind {
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            ViolationKind::Schema => "schema",
+            ViolationKind::OpenApi => "openapi",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Violation {
+    pub kind: ViolationKind,
+    pub message: String,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Request {
     pub uri: Url,
@@ -38,6 +63,8 @@ pub struct Request {
     pub response: Option<ResponseVariant>,
     pub diffs: Vec<(ChangeTag, String)>,
     pub has_diffs: bool,
+    pub violations: Vec<Violation>,
+    pub started_at: Option<DateTime<Utc>>,
 }
 
 impl Request {
@@ -57,6 +84,8 @@ impl Request {
             headers,
             body,
             has_diffs: false,
+            violations: Vec::new(),
+            started_at: None,
         }
     }
 
@@ -65,6 +94,8 @@ impl Request {
         self.job_duration = None;
         self.response = None;
         self.diffs = Vec::new();
+        self.violations = Vec::new();
+        self.started_at = None;
     }
 
     pub async fn start(&mut self) {
@@ -98,6 +129,8 @@ impl Request {
             None => {}
         };
 
+        self.started_at = Some(Utc::now());
+
         let started_at = Instant::now();
         let result = request_builder.send().await;
 
@@ -127,17 +160,63 @@ impl Request {
         self.http_method = dto.http_method;
     }
 
+    pub fn get_status_code(&self) -> Option<u16> {
+        match &self.response {
+            Some(ResponseVariant::Success(response)) => {
+                Some(response.status_code)
+            }
+            _ => None,
+        }
+    }
+
+    /// Response as compared against the baseline, status code is replaced
+    /// by the baseline one when both are equivalent
+    pub fn get_comparable_response(
+        &self,
+        expectation: &Option<ResponseExpectation>,
+        baseline_status_code: Option<u16>,
+    ) -> Option<ResponseVariant> {
+        match (&self.response, expectation, baseline_status_code) {
+            (
+                Some(ResponseVariant::Success(response)),
+                Some(expectation),
+                Some(baseline),
+            ) if expectation.are_status_codes_equivalent(
+                response.status_code,
+                baseline,
+            ) =>
+            {
+                Some(ResponseVariant::Success(Response {
+                    status_code: baseline,
+                    ..response.clone()
+                }))
+            }
+            _ => self.response.clone(),
+        }
+    }
+
     pub fn set_diffs_and_calculate_status(
         &mut self,
         diffs: Vec<(ChangeTag, String)>,
+        expectation: &Option<ResponseExpectation>,
     ) {
-        let has_diffs = diffs.iter().any(|(tag, _)| tag != &ChangeTag::Equal);
+        let status_code = self.get_status_code();
+
+        let has_diffs =
+            diffs.iter().any(|(tag, _)| !matches!(tag, ChangeTag::Equal));
 
         self.has_diffs = has_diffs;
 
         self.diffs = diffs;
 
-        if has_diffs {
+        let is_status_code_allowed = match (expectation, status_code) {
+            (Some(expectation), Some(status_code)) => {
+                expectation.is_status_code_allowed(&self.uri, status_code)
+            }
+            _ => true,
+        };
+
+        if has_diffs || !is_status_code_allowed || self.has_violations() {
             self.status = JobStatus::Failed;
         } else {
             let request_failed = match &self.response {
@@ -153,9 +232,21 @@ impl Request {
         }
     }
 
+    pub fn has_violations(&self) -> bool {
+        !self.violations.is_empty()
+    }
+
     pub fn get_status_text(&self) -> String {
         let is_success = match &self.status {
             JobStatus::Finished => " SUCCESS",
+            JobStatus::Failed if !self.has_diffs => {
+                match self.violations.first().map(|violation| &violation.kind)
+                {
+                    Some(ViolationKind::Schema) => " SCHEMA FAIL",
+                    Some(ViolationKind::OpenApi) => " CONTRACT FAIL",
+                    None => " FAIL",
+                }
+            }
             JobStatus::Failed => " FAIL",
             _ => " PENDING",
         };
@@ -178,6 +269,71 @@ impl Request {
             None => base_text,
         }
     }
+
+    pub fn to_curl_command(&self) -> String {
+        let mut arguments = vec![format!(
+            "curl -X {:?} {}",
+            self.http_method,
+            quote_shell_argument(self.uri.as_str())
+        )];
+
+        let headers: BTreeMap<&String, &HeaderValue> =
+            self.headers.iter().flatten().collect();
+
+        for (name, value) in headers.iter() {
+            arguments.push(format!(
+                "-H {}",
+                quote_shell_argument(&format!("{}: {}", name, value))
+            ));
+        }
+
+        if let Some(body) = &self.body {
+            let has_content_type = headers
+                .keys()
+                .any(|name| name.eq_ignore_ascii_case("content-type"));
+
+            // body is sent without content type,
+            // while curl would default to form encoding
+            if !has_content_type {
+                arguments.push("-H 'content-type:'".to_owned());
+            }
+
+            arguments.push(format!(
+                "--data-raw {}",
+                quote_shell_argument(&body.to_string())
+            ));
+        }
+
+        arguments.join(" \\\n  ")
+    }
+
+    /// Rebuilds both sides from `diffs` and formats them as unified diff
+    /// against the baseline request
+    pub fn to_unified_diff(&self, baseline_uri: &Url) -> String {
+        let mut old = String::new();
+        let mut new = String::new();
+
+        for (tag, line) in self.diffs.iter() {
+            let line = match line.ends_with('\n') {
+                true => line.to_owned(),
+                false => format!("{}\n", line),
+            };
+
+            match tag {
+                ChangeTag::Equal => {
+                    old.push_str(&line);
+                    new.push_str(&line);
+                }
+                ChangeTag::Delete => old.push_str(&line),
+                ChangeTag::Insert => new.push_str(&line),
+            }
+        }
+
+        TextDiff::from_lines(&old, &new)
+            .unified_diff()
+            .header(baseline_uri.as_str(), self.uri.as_str())
+            .to_string()
+    }
 }
 
 fn reqwest_headers_to_hashmap(reqwest_headers: &HeaderMap) -> HeadersMap {
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use similar::ChangeTag;
+use url::Url;
+
+use super::request::{Request, Response, ResponseVariant};
+use crate::http_diff::types::{
+    HeaderValue, HttpMethod, JobStatus, ResponseExpectation,
+    StatusCodeExpectation,
+};
+
+fn create_request_with_status_code(uri: &str, status_code: u16) -> Request {
+    let mut request =
+        Request::new(&Url::parse(uri).unwrap(), &HttpMethod::GET, None, None);
+
+    request.response = Some(ResponseVariant::Success(Response {
+        status_code,
+        content_length: None,
+        headers: HashMap::new(),
+        body: None,
+    }));
+
+    request
+}
+
+fn create_status_code_diffs(old: u16, new: u16) -> Vec<(ChangeTag, String)> {
+    vec![
+        (ChangeTag::Equal, "{\n".to_owned()),
+        (ChangeTag::Delete, format!("  \"status_code\": {},\n", old)),
+        (ChangeTag::Insert, format!("  \"status_code\": {},\n", new)),
+        (ChangeTag::Equal, "}\n".to_owned()),
+    ]
+}
+
+#[test]
+pub fn test_identical_responses_with_unexpected_status_code_fail() {
+    let mut request =
+        create_request_with_status_code("http://domain-a.com/health", 500);
+
+    let expectation = Some(ResponseExpectation {
+        status: Some(StatusCodeExpectation::AllDomains(vec![200])),
+        equivalent_status: None,
+    });
+
+    request.set_diffs_and_calculate_status(
+        vec![(ChangeTag::Equal, "{}".to_owned())],
+        &expectation,
+    );
+
+    assert!(!request.has_diffs);
+    assert_eq!(request.status, JobStatus::Failed);
+}
+
+#[test]
+pub fn test_equivalent_status_codes_are_normalized() {
+    let request =
+        create_request_with_status_code("http://domain-b.com/users", 201);
+
+    let expectation = Some(ResponseExpectation {
+        status: None,
+        equivalent_status: Some(vec![vec![200, 201], vec![404, 410]]),
+    });
+
+    let baseline =
+        create_request_with_status_code("http://domain-a.com/users", 200);
+
+    assert_eq!(
+        request.get_comparable_response(&expectation, Some(200)),
+        baseline.response
+    );
+
+    // the stored response keeps its own status code
+    assert_eq!(request.get_status_code(), Some(201));
+}
+
+#[test]
+pub fn test_non_equivalent_status_codes_are_treated_as_diffs() {
+    let mut request =
+        create_request_with_status_code("http://domain-b.com/users", 404);
+
+    let expectation = Some(ResponseExpectation {
+        status: None,
+        equivalent_status: Some(vec![vec![200, 201], vec![404, 410]]),
+    });
+
+    assert_eq!(
+        request.get_comparable_response(&expectation, Some(200)),
+        request.response
+    );
+
+    request.set_diffs_and_calculate_status(
+        create_status_code_diffs(200, 404),
+        &expectation,
+    );
+
+    assert!(request.has_diffs);
+    assert_eq!(request.status, JobStatus::Failed);
+}
+
+#[test]
+pub fn test_status_code_fields_of_body_are_not_normalized() {
+    let mut request =
+        create_request_with_status_code("http://domain-b.com/users", 201);
+
+    if let Some(ResponseVariant::Success(response)) = &mut request.response {
+        response.body = Some(json!({ "status_code": 201 }));
+    }
+
+    let expectation = Some(ResponseExpectation {
+        status: None,
+        equivalent_status: Some(vec![vec![200, 201]]),
+    });
+
+    let expected = ResponseVariant::Success(Response {
+        status_code: 200,
+        content_length: None,
+        headers: HashMap::new(),
+        body: Some(json!({ "status_code": 201 })),
+    });
+
+    assert_eq!(
+        request.get_comparable_response(&expectation, Some(200)),
+        Some(expected)
+    );
+}
+
+#[test]
+pub fn test_status_code_expectation_per_domain() {
+    let expectation = Some(ResponseExpectation {
+        status: Some(StatusCodeExpectation::PerDomain(HashMap::from([(
+            Url::parse("http://domain-b.com").unwrap(),
+            vec![201],
+        )]))),
+        equivalent_status: None,
+    });
+
+    let mut first_request =
+        create_request_with_status_code("http://domain-a.com/users", 201);
+
+    first_request.set_diffs_and_calculate_status(Vec::new(), &expectation);
+
+    assert_eq!(first_request.status, JobStatus::Finished);
+
+    let mut second_request =
+        create_request_with_status_code("http://domain-b.com/users", 200);
+
+    second_request.set_diffs_and_calculate_status(Vec::new(), &expectation);
+
+    assert_eq!(second_request.status, JobStatus::Failed);
+}
+
+#[test]
+pub fn test_status_code_expectation_per_domain_path() {
+    let expectation = ResponseExpectation {
+        status: Some(StatusCodeExpectation::PerDomain(HashMap::from([
+            (Url::parse("http://domain-a.com/v1").unwrap(), vec![200]),
+            (Url::parse("http://domain-a.com/v2/").unwrap(), vec![404]),
+            (Url::parse("http://domain-a.com").unwrap(), vec![500]),
+        ]))),
+        equivalent_status: None,
+    };
+
+    let allowed = |uri: &str| {
+        expectation
+            .get_allowed_status_codes(&Url::parse(uri).unwrap())
+            .cloned()
+    };
+
+    assert_eq!(allowed("http://domain-a.com/v1/users"), Some(vec![200]));
+    assert_eq!(allowed("http://domain-a.com/v2/users"), Some(vec![404]));
+    assert_eq!(allowed("http://domain-a.com/v10/users"), Some(vec![500]));
+    assert_eq!(allowed("http://domain-b.com/v1/users"), None);
+}
+
+#[test]
+pub fn test_allowed_status_codes_respect_equivalence() {
+    let expectation = ResponseExpectation {
+        status: Some(StatusCodeExpectation::AllDomains(vec![200])),
+        equivalent_status: Some(vec![vec![200, 201]]),
+    };
+
+    let uri = Url::parse("http://domain-a.com/users").unwrap();
+
+    assert!(expectation.is_status_code_allowed(&uri, 201));
+    assert!(!expectation.is_status_code_allowed(&uri, 204));
+}
+
+#[test]
+pub fn test_request_to_curl_command() {
+    let request = Request::new(
+        &Url::parse("http://domain-a.com/api/users").unwrap(),
+        &HttpMethod::POST,
+        Some(HashMap::from([
+            ("x-test".to_owned(), HeaderValue::String("it's".to_owned())),
+            ("cookie".to_owned(), HeaderValue::String("auth=1".to_owned())),
+        ])),
+        Some(json!({ "username": "test" })),
+    );
+
+    let expected = r#"curl -X POST 'http://domain-a.com/api/users' \
+  -H 'cookie: auth=1' \
+  -H 'x-test: it'\''s' \
+  -H 'content-type:' \
+  --data-raw '{"username":"test"}'"#;
+
+    assert_eq!(request.to_curl_command(), expected);
+
+    let request = Request::new(
+        &Url::parse("http://domain-a.com/health").unwrap(),
+        &HttpMethod::GET,
+        None,
+        None,
+    );
+
+    assert_eq!(
+        request.to_curl_command(),
+        "curl -X GET 'http://domain-a.com/health'"
+    );
+}
+
+#[test]
+pub fn test_request_to_unified_diff() {
+    let mut request =
+        create_request_with_status_code("http://domain-b.com/api/users", 500);
+
+    request.diffs = create_status_code_diffs(200, 500);
+
+    let expected = "--- http://domain-a.com/api/users
++++ http://domain-b.com/api/users
+@@ -1,3 +1,3 @@
+ {
+-  \"status_code\": 200,
++  \"status_code\": 500,
+ }
+";
+
+    assert_eq!(
+        request.to_unified_diff(
+            &Url::parse("http://domain-a.com/api/users").unwrap()
+        ),
+        expected
+    );
+}
@@ -1,7 +1,10 @@
 use reqwest::header::HeaderValue as ReqwestHeaderValue;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::Value;
+use std::{collections::HashMap, fmt};
 use thiserror::Error;
+use url::Url;
 
 #[derive(Error, Debug, Clone, PartialEq)]
 pub enum AppError {
@@ -25,7 +28,7 @@ impl From<anyhow::Error> for AppError {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
 pub enum HttpMethod {
     GET,
     POST,
@@ -34,6 +37,19 @@ pub enum HttpMethod {
     DELETE,
 }
 
+impl HttpMethod {
+    pub fn from_name(name: &str) -> Option<HttpMethod> {
+        match name.to_uppercase().as_str() {
+            "GET" => Some(HttpMethod::GET),
+            "POST" => Some(HttpMethod::POST),
+            "PUT" => Some(HttpMethod::PUT),
+            "PATCH" => Some(HttpMethod::PATCH),
+            "DELETE" => Some(HttpMethod::DELETE),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub enum JobStatus {
     Pending,
@@ -42,12 +58,12 @@ pub enum JobStatus {
     Finished,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
 pub enum VariableGenerator {
     UUID,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(untagged)]
 pub enum PathVariableValue {
     Generator(VariableGenerator),
@@ -55,7 +71,7 @@ pub enum PathVariableValue {
     String(String),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(untagged)]
 pub enum PathVariable {
     SingleValue(PathVariableValue),
@@ -64,13 +80,22 @@ pub enum PathVariable {
 
 pub type VariablesMap = HashMap<String, PathVariable>;
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
 #[serde(untagged)]
 pub enum HeaderValue {
     String(String),
     U64(u64),
 }
 
+impl fmt::Display for HeaderValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HeaderValue::String(value) => write!(f, "{}", value),
+            HeaderValue::U64(value) => write!(f, "{}", value),
+        }
+    }
+}
+
 impl From<&HeaderValue> for ReqwestHeaderValue {
     fn from(value: &HeaderValue) -> Self {
         match value {
@@ -100,3 +125,71 @@ impl Into<ReqwestHeaderValue> for HeaderValue {
 pub type HeadersMap = HashMap<String, HeaderValue>;
 
 pub type PlaceholderToValueMap = HashMap<String, PathVariableValue>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum StatusCodeExpectation {
+    AllDomains(Vec<u16>),
+    PerDomain(HashMap<Url, Vec<u16>>),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct ResponseExpectation {
+    pub status: Option<StatusCodeExpectation>,
+    pub equivalent_status: Option<Vec<Vec<u16>>>,
+}
+
+impl ResponseExpectation {
+    pub fn are_status_codes_equivalent(&self, left: u16, right: u16) -> bool {
+        if left == right {
+            return true;
+        }
+
+        match &self.equivalent_status {
+            Some(groups) => groups
+                .iter()
+                .any(|group| group.contains(&left) && group.contains(&right)),
+            None => false,
+        }
+    }
+
+    pub fn get_allowed_status_codes(&self, uri: &Url) -> Option<&Vec<u16>> {
+        match &self.status {
+            Some(StatusCodeExpectation::AllDomains(status_codes)) => {
+                Some(status_codes)
+            }
+            // the most specific domain wins when several are prefixes of uri
+            Some(StatusCodeExpectation::PerDomain(domains)) => domains
+                .iter()
+                .filter(|(domain, _)| is_domain_of_uri(domain, uri))
+                .max_by_key(|(domain, _)| domain.path().len())
+                .map(|(_, status_codes)| status_codes),
+            None => None,
+        }
+    }
+
+    pub fn is_status_code_allowed(&self, uri: &Url, status_code: u16) -> bool {
+        match self.get_allowed_status_codes(uri) {
+            Some(status_codes) => status_codes.iter().any(|allowed| {
+                self.are_status_codes_equivalent(*allowed, status_code)
+            }),
+            None => true,
+        }
+    }
+}
+
+/// Whether uri is under the configured domain, including its path
+fn is_domain_of_uri(domain: &Url, uri: &Url) -> bool {
+    let domain_path = domain.path().trim_end_matches('/');
+
+    domain.origin() == uri.origin()
+        && (uri.path() == domain_path
+            || uri.path().starts_with(&format!("{}/", domain_path)))
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum ResponseSchema {
+    Path(String),
+    Inline(Value),
+}
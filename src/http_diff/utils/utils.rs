@@ -1,11 +1,15 @@
 use std::time::Duration;
 
 use regex::Regex;
+use url::Url;
 
 use crate::http_diff::types::PathVariable;
 use rand::seq::SliceRandom;
+use uuid::Uuid;
 
-use super::super::types::{PlaceholderToValueMap, VariablesMap};
+use super::super::types::{
+    PathVariableValue, PlaceholderToValueMap, VariableGenerator, VariablesMap,
+};
 
 pub fn get_placeholders_from_string(input_string: &str) -> Vec<String> {
     let re = Regex::new(r#"<([^>]+)>"#).unwrap();
@@ -34,6 +38,39 @@ pub fn replace_placeholder_with_value(
     result.to_string()
 }
 
+pub fn format_endpoint_with_variables(
+    endpoint: &str,
+    variables_combination: &PlaceholderToValueMap,
+    placeholders: &Vec<&String>,
+) -> String {
+    let mut formatted_string = endpoint.to_owned();
+
+    for placeholder in placeholders {
+        let value = variables_combination.get(*placeholder);
+        if value.is_none() {
+            continue;
+        }
+
+        let value_for_replacement = match value.unwrap() {
+            PathVariableValue::Generator(generator_type) => {
+                match generator_type {
+                    VariableGenerator::UUID => Uuid::new_v4().to_string(),
+                }
+            }
+            PathVariableValue::String(string_value) => string_value.clone(),
+            PathVariableValue::Int(int_value) => int_value.to_string(),
+        };
+
+        formatted_string = replace_placeholder_with_value(
+            &formatted_string,
+            placeholder,
+            &value_for_replacement,
+        );
+    }
+
+    formatted_string
+}
+
 pub fn flatten_variables_map(map: VariablesMap) -> Vec<PlaceholderToValueMap> {
     // Get the keys and values as vectors of tuples
     let key_value_pairs: Vec<(&String, &PathVariable)> = map.iter().collect();
@@ -106,6 +143,17 @@ pub fn clean_special_chars_for_filename(input: &str) -> String {
     cleaned_string.into_owned()
 }
 
+pub fn get_endpoint_from_url(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_owned(),
+    }
+}
+
+pub fn quote_shell_argument(argument: &str) -> String {
+    format!("'{}'", argument.replace('\'', r"'\''"))
+}
+
 pub fn prettify_duration(duration: Duration) -> String {
     match duration {
         d if d < Duration::from_secs(1) => {
@@ -0,0 +1,10 @@
+pub mod openapi;
+pub mod schema;
+
+#[cfg(test)]
+mod openapi_tests;
+#[cfg(test)]
+mod schema_tests;
+
+pub use openapi::*;
+pub use schema::*;
@@ -0,0 +1,464 @@
+use jsonschema::Validator;
+use regex::Regex;
+use serde_json::Value;
+use std::{fs::File, io::Read, path::Path};
+use url::Url;
+
+use super::super::request::Response;
+use super::super::types::{AppError, HeaderValue, HttpMethod};
+use super::schema::get_schema_violations;
+
+#[derive(Debug)]
+pub struct MediaTypeContract {
+    pub media_type: String,
+    pub validator: Option<Validator>,
+}
+
+#[derive(Debug)]
+pub struct ResponseContract {
+    pub status: String,
+    pub content: Vec<MediaTypeContract>,
+}
+
+#[derive(Debug)]
+pub struct OperationContract {
+    pub http_method: HttpMethod,
+    pub path_template: String,
+    path_regex: Regex,
+    pub responses: Vec<ResponseContract>,
+}
+
+#[derive(Debug)]
+pub struct OpenApiSpecification {
+    pub base_paths: Vec<String>,
+    pub operations: Vec<OperationContract>,
+}
+
+impl OpenApiSpecification {
+    pub fn load(file_path: &str) -> Result<OpenApiSpecification, AppError> {
+        let document = load_openapi_document(file_path)?;
+
+        OpenApiSpecification::from_document(&document)
+    }
+
+    pub fn from_document(
+        document: &Value,
+    ) -> Result<OpenApiSpecification, AppError> {
+        let paths = document.get("paths").and_then(Value::as_object).ok_or(
+            AppError::ValidationError(
+                "OpenAPI document does not define any paths".into(),
+            ),
+        )?;
+
+        let mut operations = Vec::new();
+
+        for (path_template, path_item) in paths {
+            let path_item = resolve_reference(document, path_item);
+
+            for (method_name, http_method) in [
+                ("get", HttpMethod::GET),
+                ("post", HttpMethod::POST),
+                ("put", HttpMethod::PUT),
+                ("patch", HttpMethod::PATCH),
+                ("delete", HttpMethod::DELETE),
+            ] {
+                let operation = match path_item.get(method_name) {
+                    Some(operation) => operation,
+                    None => continue,
+                };
+
+                let responses = map_operation_responses(
+                    document,
+                    operation,
+                    &format!("{} {}", method_name, path_template),
+                )?;
+
+                operations.push(OperationContract {
+                    http_method,
+                    path_template: path_template.clone(),
+                    path_regex: path_template_to_regex(path_template),
+                    responses,
+                });
+            }
+        }
+
+        let base_paths = document
+            .get("servers")
+            .and_then(Value::as_array)
+            .map(|servers| {
+                servers
+                    .iter()
+                    .filter_map(|server| server.get("url")?.as_str())
+                    .filter_map(get_server_base_path)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(OpenApiSpecification { base_paths, operations })
+    }
+
+    pub fn find_operation(
+        &self,
+        http_method: &HttpMethod,
+        path: &str,
+    ) -> Option<&OperationContract> {
+        let mut candidate_paths = vec![path];
+
+        for base_path in self.base_paths.iter() {
+            if let Some(stripped) = path.strip_prefix(base_path.as_str()) {
+                candidate_paths.push(stripped);
+            }
+        }
+
+        self.operations
+            .iter()
+            .filter(|operation| &operation.http_method == http_method)
+            .filter(|operation| {
+                candidate_paths
+                    .iter()
+                    .any(|path| operation.path_regex.is_match(path))
+            })
+            .min_by_key(|operation| {
+                operation.path_template.matches('{').count()
+            })
+    }
+
+    pub fn get_violations(
+        &self,
+        http_method: &HttpMethod,
+        uri: &Url,
+        response: &Response,
+    ) -> Vec<String> {
+        let operation = match self.find_operation(http_method, uri.path()) {
+            Some(operation) => operation,
+            None => {
+                return vec![format!(
+                    "no operation matches {:?} {}",
+                    http_method,
+                    uri.path()
+                )]
+            }
+        };
+
+        let operation_name =
+            format!("{:?} {}", operation.http_method, operation.path_template);
+
+        let response_contract =
+            match operation.find_response(response.status_code) {
+                Some(response_contract) => response_contract,
+                None => {
+                    return vec![format!(
+                        "{}: status code {} is not declared",
+                        operation_name, response.status_code
+                    )]
+                }
+            };
+
+        if response_contract.content.is_empty() {
+            return Vec::new();
+        }
+
+        let content_type = match response.headers.get("content-type") {
+            Some(HeaderValue::String(content_type)) => {
+                get_media_type(content_type)
+            }
+            _ => {
+                return vec![format!(
+                    "{}: response has no content type",
+                    operation_name
+                )]
+            }
+        };
+
+        let media_type_contract =
+            response_contract.content.iter().find(|contract| {
+                is_media_type_matching(&contract.media_type, &content_type)
+            });
+
+        let media_type_contract = match media_type_contract {
+            Some(media_type_contract) => media_type_contract,
+            None => {
+                return vec![format!(
+                    "{}: content type {} is not declared for status code {}",
+                    operation_name, content_type, response.status_code
+                )]
+            }
+        };
+
+        match &media_type_contract.validator {
+            Some(validator) if is_json_media_type(&content_type) => {
+                get_schema_violations(validator, &response.body)
+                    .into_iter()
+                    .map(|violation| {
+                        format!("{}: {}", operation_name, violation)
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+pub fn load_openapi_document(file_path: &str) -> Result<Value, AppError> {
+    let mut file = File::open(file_path)
+        .map_err(|_| AppError::FileNotFound(file_path.to_string()))?;
+
+    let mut buffer = String::new();
+
+    file.read_to_string(&mut buffer)
+        .map_err(|error| AppError::FailedToParseConfig(error.to_string()))?;
+
+    let extension = Path::new(file_path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+
+    let document = match extension {
+        "yaml" | "yml" => serde_yaml::from_str(&buffer).map_err(|error| {
+            AppError::FailedToParseConfig(format!("{}: {}", file_path, error))
+        })?,
+        _ => serde_json::from_str(&buffer).map_err(|error| {
+            AppError::FailedToParseConfig(format!("{}: {}", file_path, error))
+        })?,
+    };
+
+    Ok(document)
+}
+
+impl OperationContract {
+    pub fn find_response(
+        &self,
+        status_code: u16,
+    ) -> Option<&ResponseContract> {
+        let exact = status_code.to_string();
+        let range = format!("{}XX", status_code / 100);
+
+        self.responses
+            .iter()
+            .find(|response| response.status == exact)
+            .or_else(|| {
+                self.responses.iter().find(|response| {
+                    response.status.eq_ignore_ascii_case(&range)
+                })
+            })
+            .or_else(|| {
+                self.responses
+                    .iter()
+                    .find(|response| response.status == "default")
+            })
+    }
+}
+
+fn map_operation_responses(
+    document: &Value,
+    operation: &Value,
+    operation_name: &str,
+) -> Result<Vec<ResponseContract>, AppError> {
+    let responses = match operation.get("responses").and_then(Value::as_object)
+    {
+        Some(responses) => responses,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut contracts = Vec::with_capacity(responses.len());
+
+    for (status, response) in responses {
+        let response = resolve_reference(document, response);
+
+        let mut content = Vec::new();
+
+        if let Some(media_types) =
+            response.get("content").and_then(Value::as_object)
+        {
+            for (media_type, media_type_object) in media_types {
+                let validator = match media_type_object.get("schema") {
+                    Some(schema) => Some(
+                        compile_schema_with_components(document, schema)
+                            .map_err(|error| {
+                                AppError::ValidationError(format!(
+                                    "Invalid OpenAPI schema for {} {}: {}",
+                                    operation_name, status, error
+                                ))
+                            })?,
+                    ),
+                    None => None,
+                };
+
+                content.push(MediaTypeContract {
+                    media_type: media_type.to_lowercase(),
+                    validator,
+                });
+            }
+        }
+
+        contracts.push(ResponseContract { status: status.clone(), content });
+    }
+
+    Ok(contracts)
+}
+
+fn compile_schema_with_components(
+    document: &Value,
+    schema: &Value,
+) -> Result<Validator, String> {
+    let mut schema = schema.clone();
+    let mut components = document.get("components").cloned();
+
+    let version =
+        document.get("openapi").and_then(Value::as_str).unwrap_or_default();
+
+    if version.starts_with("3.0") {
+        translate_openapi_3_0_schema(&mut schema);
+
+        if let Some(components) = &mut components {
+            translate_openapi_3_0_schema(components);
+        }
+    }
+
+    // schemas reference each other via `#/components/...`,
+    // so components are embedded into the schema root to resolve them
+    if let (Value::Object(schema_object), Some(components)) =
+        (&mut schema, components)
+    {
+        schema_object.entry("components").or_insert(components);
+    }
+
+    jsonschema::validator_for(&schema).map_err(|error| error.to_string())
+}
+
+/// Rewrites OpenAPI 3.0 schema keywords into their JSON Schema equivalents,
+/// `nullable`, siblings of `$ref` and boolean exclusive bounds
+fn translate_openapi_3_0_schema(schema: &mut Value) {
+    match schema {
+        Value::Object(object) => {
+            // siblings of `$ref` such as `example` are ignored in 3.0
+            if object.contains_key("$ref") {
+                object.retain(|key, _| key == "$ref");
+
+                return;
+            }
+
+            if let Some(Value::Bool(nullable)) = object.remove("nullable") {
+                if nullable {
+                    add_null_to_schema(object);
+                }
+            }
+
+            for (exclusive_key, bound_key) in [
+                ("exclusiveMinimum", "minimum"),
+                ("exclusiveMaximum", "maximum"),
+            ] {
+                if let Some(Value::Bool(exclusive)) =
+                    object.get(exclusive_key).cloned()
+                {
+                    object.remove(exclusive_key);
+
+                    match object.remove(bound_key) {
+                        Some(bound) if exclusive => {
+                            object.insert(exclusive_key.to_owned(), bound);
+                        }
+                        Some(bound) => {
+                            object.insert(bound_key.to_owned(), bound);
+                        }
+                        None => {}
+                    }
+                }
+            }
+
+            for value in object.values_mut() {
+                translate_openapi_3_0_schema(value);
+            }
+        }
+        Value::Array(items) => {
+            for item in items.iter_mut() {
+                translate_openapi_3_0_schema(item);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn add_null_to_schema(object: &mut serde_json::Map<String, Value>) {
+    let null = Value::String("null".to_owned());
+
+    match object.get_mut("type") {
+        Some(Value::String(schema_type)) => {
+            let schema_type = Value::String(schema_type.clone());
+
+            object.insert(
+                "type".to_owned(),
+                Value::Array(vec![schema_type, null]),
+            );
+        }
+        Some(Value::Array(types)) if !types.contains(&null) => {
+            types.push(null);
+        }
+        _ => {}
+    }
+
+    if let Some(Value::Array(values)) = object.get_mut("enum") {
+        if !values.contains(&Value::Null) {
+            values.push(Value::Null);
+        }
+    }
+}
+
+pub fn resolve_reference<'a>(
+    document: &'a Value,
+    value: &'a Value,
+) -> &'a Value {
+    match value.get("$ref").and_then(Value::as_str) {
+        Some(reference) => reference
+            .strip_prefix('#')
+            .and_then(|pointer| document.pointer(pointer))
+            .unwrap_or(value),
+        None => value,
+    }
+}
+
+fn path_template_to_regex(path_template: &str) -> Regex {
+    let parameter_pattern = Regex::new(r"\\\{[^}]*\\\}").unwrap();
+
+    let escaped = regex::escape(path_template.trim_end_matches('/'));
+
+    let pattern = parameter_pattern.replace_all(&escaped, "[^/]+");
+
+    Regex::new(&format!("^{}/?$", pattern)).unwrap()
+}
+
+fn get_server_base_path(server_url: &str) -> Option<String> {
+    let path = if server_url.starts_with('/') {
+        server_url.to_owned()
+    } else {
+        Url::parse(server_url).ok()?.path().to_owned()
+    };
+
+    let path = path.trim_end_matches('/');
+
+    if path.is_empty() {
+        None
+    } else {
+        Some(path.to_owned())
+    }
+}
+
+fn get_media_type(content_type: &str) -> String {
+    content_type.split(';').next().unwrap_or_default().trim().to_lowercase()
+}
+
+fn is_media_type_matching(declared: &str, actual: &str) -> bool {
+    if declared == "*/*" || declared == actual {
+        return true;
+    }
+
+    match declared.strip_suffix("/*") {
+        Some(declared_type) => {
+            actual.split('/').next().unwrap_or_default() == declared_type
+        }
+        None => false,
+    }
+}
+
+fn is_json_media_type(media_type: &str) -> bool {
+    media_type == "application/json" || media_type.ends_with("+json")
+}
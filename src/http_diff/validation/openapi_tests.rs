@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+use url::Url;
+
+use super::openapi::OpenApiSpecification;
+use crate::http_diff::{
+    request::Response,
+    types::{HeaderValue, HttpMethod},
+};
+
+fn get_test_specification() -> OpenApiSpecification {
+    let document = json!({
+        "openapi": "3.0.3",
+        "servers": [{ "url": "https://api.example.com/api/v1" }],
+        "paths": {
+            "/users/{userId}": {
+                "get": {
+                    "responses": {
+                        "200": {
+                            "description": "user",
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/User",
+                                        "example": { "id": 1 }
+                                    }
+                                }
+                            }
+                        },
+                        "4XX": { "$ref": "#/components/responses/Error" }
+                    }
+                }
+            },
+            "/users/me": {
+                "get": {
+                    "responses": {
+                        "204": { "description": "no content" }
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": {
+                "User": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "integer" },
+                        "name": { "type": "string", "nullable": true },
+                        "age": {
+                            "type": "integer",
+                            "minimum": 0,
+                            "exclusiveMinimum": true
+                        }
+                    },
+                    "required": ["id"]
+                }
+            },
+            "responses": {
+                "Error": {
+                    "description": "error",
+                    "content": {
+                        "application/problem+json": {
+                            "schema": {
+                                "type": "object",
+                                "required": ["title"]
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    OpenApiSpecification::from_document(&document).unwrap()
+}
+
+fn create_response(
+    status_code: u16,
+    content_type: &str,
+    body: Option<Value>,
+) -> Response {
+    Response {
+        status_code,
+        content_length: None,
+        headers: HashMap::from([(
+            "content-type".to_owned(),
+            HeaderValue::String(content_type.to_owned()),
+        )]),
+        body,
+    }
+}
+
+#[test]
+pub fn test_finds_operation_by_path_template() {
+    let specification = get_test_specification();
+
+    let actual = specification
+        .find_operation(&HttpMethod::GET, "/api/v1/users/123")
+        .map(|operation| operation.path_template.as_str());
+
+    assert_eq!(actual, Some("/users/{userId}"));
+
+    let actual = specification
+        .find_operation(&HttpMethod::GET, "/users/me")
+        .map(|operation| operation.path_template.as_str());
+
+    assert_eq!(actual, Some("/users/me"));
+
+    let actual = specification.find_operation(&HttpMethod::POST, "/users/1");
+
+    assert!(actual.is_none());
+}
+
+#[test]
+pub fn test_response_matching_contract_has_no_violations() {
+    let specification = get_test_specification();
+
+    let actual = specification.get_violations(
+        &HttpMethod::GET,
+        &Url::parse("http://domain-a.com/users/123").unwrap(),
+        &create_response(
+            200,
+            "application/json; charset=utf-8",
+            Some(json!({ "id": 123 })),
+        ),
+    );
+
+    assert!(actual.is_empty());
+
+    let actual = specification.get_violations(
+        &HttpMethod::GET,
+        &Url::parse("http://domain-a.com/users/123").unwrap(),
+        &create_response(
+            404,
+            "application/problem+json",
+            Some(json!({ "title": "Not found" })),
+        ),
+    );
+
+    assert!(actual.is_empty());
+}
+
+#[test]
+pub fn test_contract_violations() {
+    let specification = get_test_specification();
+    let uri = Url::parse("http://domain-a.com/users/123").unwrap();
+
+    let test_cases = vec![
+        (
+            create_response(200, "application/json", Some(json!({}))),
+            r#"GET /users/{userId}: /: "id" is a required property"#,
+        ),
+        (
+            create_response(500, "application/json", None),
+            "GET /users/{userId}: status code 500 is not declared",
+        ),
+        (
+            create_response(200, "text/html", None),
+            "GET /users/{userId}: content type text/html is not declared for status code 200",
+        ),
+    ];
+
+    for (response, expected) in test_cases {
+        let actual =
+            specification.get_violations(&HttpMethod::GET, &uri, &response);
+
+        assert_eq!(actual, vec![expected.to_owned()])
+    }
+}
+
+#[test]
+pub fn test_unknown_operation_is_a_violation() {
+    let specification = get_test_specification();
+
+    let actual = specification.get_violations(
+        &HttpMethod::GET,
+        &Url::parse("http://domain-a.com/orders").unwrap(),
+        &create_response(200, "application/json", None),
+    );
+
+    assert_eq!(actual, vec!["no operation matches GET /orders".to_owned()])
+}
+
+#[test]
+pub fn test_openapi_3_0_schemas_are_translated() {
+    let specification = get_test_specification();
+    let uri = Url::parse("http://domain-a.com/users/123").unwrap();
+
+    let actual = specification.get_violations(
+        &HttpMethod::GET,
+        &uri,
+        &create_response(
+            200,
+            "application/json",
+            Some(json!({ "id": 123, "name": null, "age": 1 })),
+        ),
+    );
+
+    assert!(actual.is_empty());
+
+    let actual = specification.get_violations(
+        &HttpMethod::GET,
+        &uri,
+        &create_response(
+            200,
+            "application/json",
+            Some(json!({ "id": 123, "name": 1, "age": 0 })),
+        ),
+    );
+
+    assert_eq!(actual.len(), 2);
+}
@@ -0,0 +1,66 @@
+use jsonschema::Validator;
+use serde_json::Value;
+use std::{fs::File, io::Read};
+
+use super::super::types::{AppError, ResponseSchema};
+
+pub fn load_response_schema(
+    response_schema: &ResponseSchema,
+) -> Result<Value, AppError> {
+    match response_schema {
+        ResponseSchema::Inline(schema) => Ok(schema.clone()),
+        ResponseSchema::Path(file_path) => {
+            let mut file = File::open(file_path)
+                .map_err(|_| AppError::FileNotFound(file_path.to_string()))?;
+
+            let mut buffer = String::new();
+
+            file.read_to_string(&mut buffer).map_err(|error| {
+                AppError::FailedToParseConfig(error.to_string())
+            })?;
+
+            serde_json::from_str(&buffer).map_err(|error| {
+                AppError::FailedToParseConfig(format!(
+                    "{}: {}",
+                    file_path, error
+                ))
+            })
+        }
+    }
+}
+
+pub fn compile_response_schema(
+    response_schema: &ResponseSchema,
+) -> Result<Validator, AppError> {
+    let schema = load_response_schema(response_schema)?;
+
+    jsonschema::validator_for(&schema).map_err(|error| {
+        AppError::ValidationError(format!(
+            "Invalid response schema: {}",
+            error
+        ))
+    })
+}
+
+pub fn get_schema_violations(
+    validator: &Validator,
+    body: &Option<Value>,
+) -> Vec<String> {
+    match body {
+        Some(body) => validator
+            .iter_errors(body)
+            .map(|error| {
+                let instance_path = error.instance_path().to_string();
+
+                let location = if instance_path.is_empty() {
+                    "/"
+                } else {
+                    &instance_path
+                };
+
+                format!("{}: {}", location, error)
+            })
+            .collect(),
+        None => vec!["response body is not a valid json".to_owned()],
+    }
+}
@@ -0,0 +1,59 @@
+use serde_json::json;
+
+use super::schema::{compile_response_schema, get_schema_violations};
+use crate::http_diff::types::{AppError, ResponseSchema};
+
+#[test]
+pub fn test_valid_body_has_no_schema_violations() {
+    let validator = compile_response_schema(&ResponseSchema::Inline(json!({
+        "type": "object",
+        "properties": { "id": { "type": "integer" } },
+        "required": ["id"]
+    })))
+    .unwrap();
+
+    let actual = get_schema_violations(&validator, &Some(json!({ "id": 1 })));
+
+    assert!(actual.is_empty())
+}
+
+#[test]
+pub fn test_schema_violations_include_instance_path() {
+    let validator = compile_response_schema(&ResponseSchema::Inline(json!({
+        "type": "array",
+        "items": {
+            "type": "object",
+            "properties": { "id": { "type": "integer" } }
+        }
+    })))
+    .unwrap();
+
+    let actual =
+        get_schema_violations(&validator, &Some(json!([{ "id": "abc" }])));
+
+    assert_eq!(actual, vec![r#"/0/id: "abc" is not of type "integer""#])
+}
+
+#[test]
+pub fn test_missing_body_is_a_schema_violation() {
+    let validator =
+        compile_response_schema(&ResponseSchema::Inline(json!({}))).unwrap();
+
+    let actual = get_schema_violations(&validator, &None);
+
+    assert_eq!(actual.len(), 1)
+}
+
+#[test]
+pub fn test_missing_schema_file() {
+    let actual = compile_response_schema(&ResponseSchema::Path(
+        "./does-not-exist.schema.json".to_owned(),
+    ));
+
+    assert_eq!(
+        actual.err(),
+        Some(AppError::FileNotFound(
+            "./does-not-exist.schema.json".to_owned()
+        ))
+    )
+}
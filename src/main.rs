@@ -20,7 +20,10 @@ use ratatui::{
 use reducer::update_state;
 use std::{fs::File, process, sync::Arc};
 use std::{io, time::Duration};
-use std::{path::Path, time::Instant};
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
 use tokio::sync::broadcast;
 use tracing::error;
 use tracing_subscriber::{
@@ -41,7 +44,13 @@ pub mod http_diff;
 pub mod reducer;
 pub mod ui;
 pub mod worker;
-use cli::Arguments;
+use cli::{
+    commands::{
+        parse_configuration_overrides, parse_endpoint_filter, run_command,
+        run_dry_run,
+    },
+    Arguments, Command, OutputFormat, RunArguments,
+};
 
 pub fn initialize_panic_handler() {
     let original_hook = std::panic::take_hook();
@@ -92,6 +101,30 @@ async fn main() -> Result<()> {
         Ok(args) => args,
     };
 
+    let args = match args.command {
+        None => args.run,
+        Some(Command::Run(run_arguments)) => run_arguments,
+        Some(command) => {
+            if let Err(err) = run_command(&command) {
+                eprintln!("\n{}", err.to_string().red());
+
+                process::exit(1);
+            }
+
+            return Ok(());
+        }
+    };
+
+    if let Some(format) = &args.dry_run {
+        if let Err(err) = run_dry_run(&args, format).await {
+            eprintln!("\n{}", err.to_string().red());
+
+            process::exit(1);
+        }
+
+        return Ok(());
+    }
+
     if args.enable_log {
         let _ = tracing_subscriber::registry()
             .with(
@@ -116,7 +149,7 @@ async fn main() -> Result<()> {
     }
 
     if let Err(err) = res {
-        println!("\n{}", err.to_string().red());
+        eprintln!("\n{}", err.to_string().red());
 
         process::exit(1);
     }
@@ -126,7 +159,7 @@ async fn main() -> Result<()> {
 
 async fn run_app<B: Backend>(
     terminal: &mut Option<Terminal<B>>,
-    args: Arguments,
+    args: RunArguments,
 ) -> Result<()> {
     let output_directory = Path::new(&args.output_directory);
 
@@ -137,6 +170,18 @@ async fn run_app<B: Backend>(
 
     let mut app = AppState::new(&output_directory, args.headless);
 
+    app.should_export_har = args.export_har;
+    app.junit_report_path = args.report_junit.as_ref().map(PathBuf::from);
+    app.html_report_path = args.report_html.as_ref().map(PathBuf::from);
+    app.markdown_report_path =
+        args.report_markdown.as_ref().map(PathBuf::from);
+    app.markdown_report_size_limit = args.report_markdown_limit;
+    app.output_format = args.output_format.clone();
+    app.endpoint_filter = parse_endpoint_filter(&args.filter)?;
+    app.active_profile = args.overrides.profile.clone();
+    app.configuration_path = args.configuration.clone();
+    app.save_policy = args.save.clone();
+
     let (event_loop_actions_sender, mut event_loop_actions_receiver) =
         broadcast::channel::<AppAction>(1000);
 
@@ -147,10 +192,24 @@ async fn run_app<B: Backend>(
 
     let mut http_diff = HttpDiff::new(event_loop_actions_sender.clone())?;
 
-    if app.is_headless_mode {
+    http_diff.endpoint_filter = app.endpoint_filter.clone();
+    http_diff.configuration_overrides =
+        parse_configuration_overrides(&args.overrides)?;
+
+    if app.is_headless_mode && app.output_format == OutputFormat::Text {
         print_logo();
     };
 
+    if app.is_headless_mode {
+        if let Some(profile) = &app.active_profile {
+            app.print_message(&format!("Profile: {}", profile));
+        }
+
+        if !app.endpoint_filter.is_empty() {
+            app.print_message(&format!("Filter: {}", app.endpoint_filter));
+        }
+    }
+
     tokio::spawn(async move {
         loop {
             match handle_commands_to_http_diff_loop(
@@ -15,10 +15,10 @@ fn should_skip_action(app_has_exception: bool, action: &AppAction) -> bool {
 
     match action {
         AppAction::Quit
-        | AppAction::ConfigurationLoaded(_)
+        | AppAction::ConfigurationLoaded(..)
         | AppAction::GenerateDefaultConfiguration
         | AppAction::DismissNotification
-        | AppAction::SaveFailedJobs(_)
+        | AppAction::SaveJobs(_)
         | AppAction::SetNotification(_) => false,
         _ => true,
     }
@@ -81,35 +81,49 @@ pub fn update_state(
             None
         }
         AppAction::SetNotification(notification) => {
-            if app.is_headless_mode {
-                match &notification.id {
-                    &NotificationId::AllRequestsFinishedWithFails => {
-                        app.set_critical_exception(AppError::Exception(
-                            notification.body.clone(),
-                        ));
-
-                        app.set_notification(notification);
+            match &notification.id {
+                NotificationId::AllRequestsFinishedWithFails
+                | NotificationId::AllRequestsFinishedWithoutFails => {
+                    let jobs_to_save = app.get_jobs_to_save();
 
-                        let failed_jobs = app.get_failed_jobs();
+                    if app.is_headless_mode {
+                        app.export_run_results();
+                        app.print_run_records();
 
-                        if !failed_jobs.is_empty() {
-                            let _ = events_sender
-                                .send(AppAction::SaveFailedJobs(failed_jobs));
+                        if notification.id
+                            == NotificationId::AllRequestsFinishedWithFails
+                        {
+                            app.set_critical_exception(AppError::Exception(
+                                notification.body.clone(),
+                            ));
                         } else {
-                            app.set_should_quit(true)
+                            app.print_message(&format!(
+                                "\n{}",
+                                notification.body.as_str().green()
+                            ));
                         }
 
-                        return None;
+                        // quits once jobs are saved
+                        if jobs_to_save.is_empty() {
+                            app.set_should_quit(true)
+                        }
                     }
-                    NotificationId::AllRequestsFinishedWithoutFails
-                    | NotificationId::SavedJobs => {
-                        println!("\n{}", notification.body.as_str().green());
 
-                        app.set_should_quit(true)
+                    if !jobs_to_save.is_empty() {
+                        let _ = events_sender
+                            .send(AppAction::SaveJobs(jobs_to_save));
                     }
-                    _ => {}
-                };
-            }
+                }
+                NotificationId::SavedJobs if app.is_headless_mode => {
+                    app.print_message(&format!(
+                        "\n{}",
+                        notification.body.as_str().green()
+                    ));
+
+                    app.set_should_quit(true)
+                }
+                _ => {}
+            };
 
             app.set_notification(notification);
 
@@ -154,8 +168,8 @@ pub fn update_state(
 
             None
         }
-        AppAction::ConfigurationLoaded(configuration) => {
-            app.on_configuration_load(configuration);
+        AppAction::ConfigurationLoaded(configuration, warnings) => {
+            app.on_configuration_load(configuration, warnings);
             None
         }
         AppAction::LoadingJobsProgress(payload) => {
@@ -25,6 +25,38 @@ pub fn render_help_popup(frame: &mut Frame, app: &mut AppState) {
                     .italic()
                     .fg(app.theme.white),
                 ),
+                Line::from(
+                    format!(
+                        "- `Shift + a` to save all rows to output directory: {}",
+                        app.output_directory.to_str().unwrap_or("")
+                    )
+                    .italic()
+                    .fg(app.theme.white),
+                ),
+                Line::from(
+                    format!(
+                        "- `c` to export selected row as curl script to output directory: {}",
+                        app.output_directory.to_str().unwrap_or("")
+                    )
+                    .italic()
+                    .fg(app.theme.white),
+                ),
+                Line::from(
+                    format!(
+                        "- `x` to export selected row as HAR archives to output directory: {}",
+                        app.output_directory.to_str().unwrap_or("")
+                    )
+                    .italic()
+                    .fg(app.theme.white),
+                ),
+                Line::from(
+                    format!(
+                        "- `Shift + x` to export all failed rows as HAR archives to output directory: {}",
+                        app.output_directory.to_str().unwrap_or("")
+                    )
+                    .italic()
+                    .fg(app.theme.white),
+                ),
                 Line::from("- `r` to restart selected job".italic().fg(app.theme.white)),
                 Line::from(
                     "- `Shift + r` to restart all jobs"
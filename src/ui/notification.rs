@@ -104,6 +104,10 @@ pub enum NotificationId {
     SavedJob,
     SavedJobs,
     FailedToSaveJobs,
+    ExportedCurlScript,
+    FailedToExportCurlScript,
+    ExportedHar,
+    FailedToExportHar,
     PendingJobInfoError,
     JobProgressChange,
     GenerateDefaultConfig,
@@ -111,6 +115,7 @@ pub enum NotificationId {
     AllRequestsFinishedWithoutFails,
     AllRequestsFinishedWithFails,
     ReloadingConfiguration,
+    ConfigurationWarnings,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -33,9 +33,19 @@ pub fn render_selected_job(frame: &mut Frame, app: &mut AppState) {
 
             render_background(frame, popup_area, &app.theme);
 
+            let violations_height = if target_request.has_violations() {
+                cmp::min(target_request.violations.len(), 8) as u16 + 1
+            } else {
+                0
+            };
+
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints([Constraint::Min(3), Constraint::Min(10)])
+                .constraints([
+                    Constraint::Min(3),
+                    Constraint::Length(violations_height),
+                    Constraint::Min(10),
+                ])
                 .split(popup_area);
 
             let titles = selected_job_state
@@ -45,7 +55,7 @@ pub fn render_selected_job(frame: &mut Frame, app: &mut AppState) {
                 .map(|request| {
                     let title = request.uri.domain().unwrap_or("");
 
-                    if request.has_diffs {
+                    if request.has_diffs || request.has_violations() {
                         return Line::from(
                             title
                                 .black()
@@ -83,10 +93,36 @@ pub fn render_selected_job(frame: &mut Frame, app: &mut AppState) {
 
             frame.render_widget(tabs, chunks[0]);
 
+            if target_request.has_violations() {
+                let violations_block = Block::default()
+                    .title("Violations")
+                    .borders(Borders::RIGHT | Borders::LEFT | Borders::TOP)
+                    .title_style(Style::default().fg(app.theme.error))
+                    .border_style(Style::default().fg(app.theme.gray));
+
+                let lines: Vec<Line> = target_request
+                    .violations
+                    .iter()
+                    .map(|violation| {
+                        Line::from(vec![
+                            format!("{}: ", violation.kind.get_label())
+                                .fg(app.theme.error),
+                            violation.message.clone().fg(app.theme.white),
+                        ])
+                    })
+                    .collect();
+
+                let paragraph = Paragraph::new(lines)
+                    .block(violations_block)
+                    .wrap(Wrap { trim: false });
+
+                frame.render_widget(paragraph, chunks[1]);
+            }
+
             let paragraph_block = Block::default()
                 .borders(Borders::RIGHT | Borders::LEFT | Borders::BOTTOM);
 
-            let height = chunks[1].height as usize;
+            let height = chunks[2].height as usize;
 
             let lines = map_request_to_lines(
                 &app.theme,
@@ -100,14 +136,14 @@ pub fn render_selected_job(frame: &mut Frame, app: &mut AppState) {
                 .block(paragraph_block)
                 .wrap(Wrap { trim: false });
 
-            frame.render_widget(paragraph, chunks[1]);
+            frame.render_widget(paragraph, chunks[2]);
 
             frame.render_stateful_widget(
                 Scrollbar::default()
                     .orientation(ScrollbarOrientation::VerticalRight)
                     .begin_symbol(Some("↑"))
                     .end_symbol(Some("↓")),
-                chunks[1],
+                chunks[2],
                 &mut selected_job_state.vertical_scroll_state,
             );
         }
@@ -134,7 +134,7 @@ fn create_status_bar_groups(app: &mut AppState) -> BarGroup {
 }
 
 fn keys_explanation_paragraph(app: &AppState) -> Paragraph<'static> {
-    let text = vec![
+    let mut text = vec![
         Line::from("Welcome,".italic().bold().fg(app.theme.white)),
         Line::from(
             "press `h` to show help screen".italic().fg(app.theme.white),
@@ -150,6 +150,42 @@ fn keys_explanation_paragraph(app: &AppState) -> Paragraph<'static> {
         ]),
     ];
 
+    if let Some(profile) = &app.active_profile {
+        text.push(Line::from(vec![
+            "profile: ".italic().fg(app.theme.white),
+            Span::styled(
+                profile.clone(),
+                Style::default()
+                    .fg(app.theme.success)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
+    if !app.endpoint_filter.is_empty() {
+        text.push(Line::from(vec![
+            "filter: ".italic().fg(app.theme.white),
+            Span::styled(
+                app.endpoint_filter.to_string(),
+                Style::default()
+                    .fg(app.theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
+    if !app.configuration_warnings.is_empty() {
+        text.push(Line::from(vec![
+            "configuration warnings: ".italic().fg(app.theme.white),
+            Span::styled(
+                app.configuration_warnings.len().to_string(),
+                Style::default()
+                    .fg(app.theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
     Paragraph::new(text).wrap(Wrap { trim: true })
 }
 
@@ -1,6 +1,13 @@
 use crate::{
     actions::AppAction,
-    http_diff::{app::App, types::AppError},
+    http_diff::{
+        app::App,
+        config::{canonicalize_path, load_configuration_value, ENV_FILE_NAME},
+        export::{reset_manifest, save_har_archives, save_manifest},
+        job::JobDTO,
+        types::AppError,
+        utils::clean_special_chars_for_filename,
+    },
     ui::notification::{Notification, NotificationId, NotificationType},
 };
 use anyhow::Result;
@@ -11,11 +18,12 @@ use notify::{
 };
 use std::{
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
     time::Duration,
 };
 use tokio::{
     select,
-    sync::broadcast::{Receiver, Sender},
+    sync::broadcast::{error::RecvError, Receiver, Sender},
 };
 use tracing::{debug, error};
 
@@ -26,8 +34,16 @@ pub async fn process_app_action(
 ) {
     match action {
         AppAction::SaveCurrentJob(job) => {
-            match job.save(&base_output_directory).await {
-                Ok(()) => {
+            let result = match job.save(&base_output_directory).await {
+                Ok(saved_job) => {
+                    save_manifest(&base_output_directory, vec![saved_job])
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+
+            match result {
+                Ok(_) => {
                     let notification = Notification::new(
                         NotificationId::SavedJob,
                         &format!(
@@ -60,7 +76,55 @@ pub async fn process_app_action(
                 }
             };
         }
-        AppAction::SaveFailedJobs(jobs) => {
+        AppAction::ExportCurrentJobAsCurl(job) => {
+            match job.save_curl_script(&base_output_directory).await {
+                Ok(script_path) => {
+                    let notification = Notification::new(
+                        NotificationId::ExportedCurlScript,
+                        &format!(
+                            "Exported curl script to {}",
+                            script_path
+                                .canonicalize()
+                                .unwrap_or(script_path)
+                                .to_str()
+                                .unwrap_or("")
+                        ),
+                        Some(Duration::from_secs(5)),
+                        NotificationType::Success,
+                    );
+
+                    let _ = worker_actions_sender
+                        .send(AppAction::SetNotification(notification));
+                }
+                Err(error) => {
+                    let notification = Notification::new(
+                        NotificationId::FailedToExportCurlScript,
+                        "Failed to export curl script",
+                        None,
+                        NotificationType::Error,
+                    );
+
+                    let _ = worker_actions_sender
+                        .send(AppAction::SetNotification(notification));
+
+                    error!("error: {}", error);
+                }
+            };
+        }
+        AppAction::ExportCurrentJobAsHar(job) => {
+            let directory = base_output_directory
+                .join(clean_special_chars_for_filename(&job.job_name));
+
+            export_har_archives(&[job], &directory, &worker_actions_sender);
+        }
+        AppAction::ExportFailedJobsAsHar(jobs) => {
+            export_har_archives(
+                &jobs,
+                &base_output_directory,
+                &worker_actions_sender,
+            );
+        }
+        AppAction::SaveJobs(jobs) => {
             let jobs_count = jobs.len();
 
             let tasks: Vec<_> = jobs
@@ -72,7 +136,27 @@ pub async fn process_app_action(
                 })
                 .collect();
 
-            join_all(tasks).await;
+            let saved_jobs = join_all(tasks)
+                .await
+                .into_iter()
+                .filter_map(|result| match result {
+                    Ok(Ok(saved_job)) => Some(saved_job),
+                    Ok(Err(error)) => {
+                        error!("error: {}", error);
+                        None
+                    }
+                    Err(error) => {
+                        error!("error: {}", error);
+                        None
+                    }
+                })
+                .collect();
+
+            if let Err(error) =
+                save_manifest(&base_output_directory, saved_jobs).await
+            {
+                error!("error: {}", error);
+            }
 
             let jobs_display_text_part =
                 if jobs_count == 1 { "job" } else { "jobs" };
@@ -95,10 +179,51 @@ pub async fn process_app_action(
             let _ = worker_actions_sender
                 .send(AppAction::SetNotification(notification));
         }
+        AppAction::StartAllJobs => {
+            if let Err(error) = reset_manifest(&base_output_directory).await {
+                error!("error: {}", error);
+            }
+        }
         _ => {}
     }
 }
 
+fn export_har_archives(
+    jobs: &[JobDTO],
+    directory: &Path,
+    worker_actions_sender: &Sender<AppAction>,
+) {
+    let notification = match save_har_archives(jobs, directory) {
+        Ok(saved_files) => Notification::new(
+            NotificationId::ExportedHar,
+            &format!(
+                "Exported {} HAR archive(s) to {}",
+                saved_files.len(),
+                directory
+                    .canonicalize()
+                    .unwrap_or_else(|_| directory.to_path_buf())
+                    .to_str()
+                    .unwrap_or("")
+            ),
+            Some(Duration::from_secs(5)),
+            NotificationType::Success,
+        ),
+        Err(error) => {
+            error!("error: {}", error);
+
+            Notification::new(
+                NotificationId::FailedToExportHar,
+                "Failed to export HAR archives",
+                None,
+                NotificationType::Error,
+            )
+        }
+    };
+
+    let _ =
+        worker_actions_sender.send(AppAction::SetNotification(notification));
+}
+
 pub async fn handle_commands_to_http_diff_loop(
     http_diff_actions_receiver: &mut Receiver<AppAction>,
     http_diff: &mut App,
@@ -172,10 +297,14 @@ pub async fn handle_commands_to_http_diff_loop(
     }
 }
 
+/// Watches configuration file together with all files it extends
+/// or includes, its `.env` file and directories of include patterns.
+/// The set of files is refreshed every time configuration is loaded
+/// or fails to load
 pub async fn get_configuration_file_watcher<P: AsRef<Path>>(
     path: P,
     app_actions_sender: Sender<AppAction>,
-) -> anyhow::Result<PollWatcher> {
+) -> anyhow::Result<Arc<Mutex<PollWatcher>>> {
     let path_to_file = path
         .as_ref()
         .to_str()
@@ -184,28 +313,25 @@ pub async fn get_configuration_file_watcher<P: AsRef<Path>>(
         ))?
         .to_string();
 
-    let mut watcher = PollWatcher::new(
-        move |res: Result<Event, notify::Error>| match res {
-            Ok(event) => match event.kind {
-                EventKind::Modify(ModifyKind::Metadata(
-                    MetadataKind::WriteTime,
-                )) => {
-                    debug!(
-                        "configuration file was saved : {:?}",
-                        path_to_file
-                    );
+    let mut app_actions_receiver = app_actions_sender.subscribe();
 
-                    let _ = app_actions_sender.send(
-                        AppAction::ReloadConfigurationFile(
-                            path_to_file.clone(),
-                        ),
-                    );
-                }
-                event_kind => {
-                    debug!("configuration file event: {:?}", event_kind)
-                }
-            },
+    let watched_paths: Arc<Mutex<Vec<PathBuf>>> = Arc::default();
+    let event_watched_paths = watched_paths.clone();
+
+    let watcher = PollWatcher::new(
+        move |res: Result<Event, notify::Error>| match res {
+            Ok(event)
+                if is_configuration_event(&event, &event_watched_paths) =>
+            {
+                debug!("configuration file was saved : {:?}", event.paths);
 
+                let _ = app_actions_sender.send(
+                    AppAction::ReloadConfigurationFile(path_to_file.clone()),
+                );
+            }
+            Ok(event) => {
+                debug!("configuration file event: {:?}", event.kind)
+            }
             Err(e) => error!(
                 "Failed to consume update configuration file event: {:?}",
                 e
@@ -214,7 +340,137 @@ pub async fn get_configuration_file_watcher<P: AsRef<Path>>(
         Config::default().with_poll_interval(Duration::from_millis(200)),
     )?;
 
-    watcher.watch(path.as_ref(), RecursiveMode::NonRecursive)?;
+    // the same canonical paths are returned by `load_configuration_value`
+    let root_file = canonicalize_path(path.as_ref());
+
+    let watcher = Arc::new(Mutex::new(watcher));
+
+    let mut watched_directories = Vec::new();
+
+    update_watched_files(
+        &watcher,
+        &root_file,
+        &watched_paths,
+        &mut watched_directories,
+    );
+
+    let task_watcher = watcher.clone();
+
+    tokio::spawn(async move {
+        loop {
+            match app_actions_receiver.recv().await {
+                Ok(AppAction::ConfigurationLoaded(..))
+                | Ok(AppAction::SetCriticalException(_)) => {
+                    update_watched_files(
+                        &task_watcher,
+                        &root_file,
+                        &watched_paths,
+                        &mut watched_directories,
+                    )
+                }
+                Err(RecvError::Closed) => break,
+                _ => {}
+            }
+        }
+    });
 
     Ok(watcher)
 }
+
+/// Files are changed, created or removed in watched directories, only
+/// watched files and files of include directories reload configuration
+fn is_configuration_event(
+    event: &Event,
+    watched_paths: &Mutex<Vec<PathBuf>>,
+) -> bool {
+    match event.kind {
+        EventKind::Modify(ModifyKind::Metadata(MetadataKind::WriteTime))
+        | EventKind::Create(_)
+        | EventKind::Remove(_) => {}
+        _ => return false,
+    }
+
+    let watched_paths = match watched_paths.lock() {
+        Ok(watched_paths) => watched_paths,
+        Err(_) => return false,
+    };
+
+    event.paths.iter().any(|path| {
+        let is_in_include_directory = path.parent().is_some_and(|parent| {
+            watched_paths.iter().any(|watched| watched == parent)
+                && parent.is_dir()
+        });
+
+        watched_paths.contains(path)
+            || (is_in_include_directory && !path.is_dir())
+    })
+}
+
+/// Directories of watched files are polled instead of the files,
+/// so files that don't exist yet are picked up once created
+fn update_watched_files(
+    watcher: &Mutex<PollWatcher>,
+    root_file: &Path,
+    watched_paths: &Mutex<Vec<PathBuf>>,
+    watched_directories: &mut Vec<PathBuf>,
+) {
+    let mut files = Vec::new();
+
+    // files are collected even if some of them fail to parse
+    let _ = load_configuration_value(root_file, &mut files);
+
+    files.push(
+        root_file
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(ENV_FILE_NAME),
+    );
+
+    let mut directories: Vec<PathBuf> = Vec::new();
+
+    for file in files.iter() {
+        let directory = match file.is_dir() {
+            true => file.as_path(),
+            false => match file.parent() {
+                Some(directory) => directory,
+                None => continue,
+            },
+        };
+
+        if !directories.iter().any(|watched| watched == directory) {
+            directories.push(directory.to_path_buf());
+        }
+    }
+
+    if let Ok(mut watched_paths) = watched_paths.lock() {
+        *watched_paths = files;
+    }
+
+    let mut watcher = match watcher.lock() {
+        Ok(watcher) => watcher,
+        Err(_) => return,
+    };
+
+    for directory in watched_directories
+        .iter()
+        .filter(|directory| !directories.contains(directory))
+    {
+        let _ = watcher.unwatch(directory);
+    }
+
+    for directory in directories
+        .iter()
+        .filter(|directory| !watched_directories.contains(directory))
+    {
+        if let Err(error) =
+            watcher.watch(directory, RecursiveMode::NonRecursive)
+        {
+            error!(
+                "Failed to watch configuration directory {:?}: {}",
+                directory, error
+            );
+        }
+    }
+
+    *watched_directories = directories;
+}